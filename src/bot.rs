@@ -1,31 +1,224 @@
+use futures::future::{BoxFuture, FutureExt};
+use futures::stream::{self, StreamExt};
 use serenity::all::{
-    CommandInteraction, CreateInteractionResponse, CreateInteractionResponseMessage,
-    EditInteractionResponse,
+    CommandInteraction, CreateAutocompleteResponse, CreateInteractionResponse,
+    CreateInteractionResponseFollowup, CreateInteractionResponseMessage, EditInteractionResponse,
 };
 use serenity::async_trait;
 use serenity::client::{Context, EventHandler};
 use serenity::model::gateway::Ready;
 use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::time::Duration;
+
+use crate::collectors::{
+    AclAnthologyCollector, AdsCollector, Article, ArxivCollector, BioRxivCollector,
+    BlueskyCollector, Collector, CollectorResult, CoreCollector, CrossrefCollector, DblpCollector,
+    DevToCollector, EuropePmcCollector, ExampleArticleCollector, FeedCollector, GitHubCollector,
+    HackerNewsCollector, HuggingFacePapersCollector, IetfCollector, InspireHepCollector,
+    LobstersCollector, MastodonCollector, OpenAlexCollector, OpenReviewCollector, PubMedCollector,
+    RedditCollector, ScrapeCollector, ScrapeConfig, SemanticScholarCollector,
+    StackExchangeCollector, ZenodoCollector,
+};
 
-use crate::collectors::{Article, ArxivCollector, Collector, ExampleArticleCollector};
+/// Collectors queried at once by `source:all` and scheduled runs
+const CONCURRENT_COLLECTORS: usize = 8;
+/// Keeps `source:all` well inside Discord's 15 minutes for editing a
+/// deferred response, even with every batch running long
+const COLLECTOR_DEADLINE: Duration = Duration::from_secs(90);
 
 pub struct Bot {
-    collectors: Arc<Mutex<Vec<Box<dyn Collector>>>>,
-    default_query: String,
+    /// Fixed after `new`, so commands share it without locking
+    collectors: Arc<Vec<Box<dyn Collector>>>,
     default_max_results: usize,
 }
 
 impl Bot {
     pub fn new(default_query: String, default_max_results: usize) -> Self {
-        let collectors: Vec<Box<dyn Collector>> = vec![
-            Box::new(ArxivCollector::new()),
+        let mut semantic_scholar = SemanticScholarCollector::new();
+        if let Some(api_key) = env_var("SEMANTIC_SCHOLAR_API_KEY") {
+            semantic_scholar = semantic_scholar.with_api_key(api_key);
+        }
+
+        let mut openalex = OpenAlexCollector::new();
+        if let Some(mailto) = env_var("OPENALEX_MAILTO") {
+            openalex = openalex.with_mailto(mailto);
+        }
+
+        let mut crossref = CrossrefCollector::new();
+        if let Some(mailto) = env_var("CROSSREF_MAILTO") {
+            crossref = crossref.with_mailto(mailto);
+        }
+
+        let mut core = CoreCollector::new();
+        if let Some(api_key) = env_var("CORE_API_KEY") {
+            core = core.with_api_key(api_key);
+        }
+
+        let mut pubmed = PubMedCollector::new();
+        if let Some(api_key) = env_var("NCBI_API_KEY") {
+            pubmed = pubmed.with_api_key(api_key);
+        }
+        if let Some(tool) = env_var("NCBI_TOOL") {
+            pubmed = pubmed.with_tool(tool);
+        }
+        if let Some(email) = env_var("NCBI_EMAIL") {
+            pubmed = pubmed.with_email(email);
+        }
+
+        let mut github = GitHubCollector::new();
+        if let Some(base_url) = env_var("GITHUB_API_URL") {
+            github = github.with_base_url(base_url);
+        }
+        if let Some(token) = env_var("GITHUB_TOKEN") {
+            github = github.with_token(token);
+        }
+        if let Some(repos) = env_var("GITHUB_WATCHED_REPOS") {
+            github = github.with_watched_repos(
+                repos
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|repo| !repo.is_empty())
+                    .map(String::from)
+                    .collect(),
+            );
+        }
+
+        let mut stackexchange = StackExchangeCollector::new();
+        if let Some(site) = env_var("STACKEXCHANGE_SITE") {
+            stackexchange = stackexchange.with_site(site);
+        }
+        if let Some(key) = env_var("STACKEXCHANGE_KEY") {
+            stackexchange = stackexchange.with_key(key);
+        }
+
+        let mut zenodo = ZenodoCollector::new();
+        if let Some(access_token) = env_var("ZENODO_ACCESS_TOKEN") {
+            zenodo = zenodo.with_access_token(access_token);
+        }
+
+        let mut mastodon = MastodonCollector::new();
+        if let Some(instance) = env_var("MASTODON_INSTANCE") {
+            mastodon = mastodon.with_base_url(instance);
+        }
+        if let Some(access_token) = env_var("MASTODON_ACCESS_TOKEN") {
+            mastodon = mastodon.with_access_token(access_token);
+        }
+        if let Some(hashtags) = env_var("MASTODON_HASHTAGS") {
+            mastodon = mastodon.with_hashtags(
+                hashtags
+                    .split(',')
+                    .map(|tag| tag.trim().trim_start_matches('#'))
+                    .filter(|tag| !tag.is_empty())
+                    .map(String::from)
+                    .collect(),
+            );
+        }
+
+        let mut acl_anthology = AclAnthologyCollector::new();
+        if let Some(cache_path) = env_var("ACL_ANTHOLOGY_CACHE") {
+            acl_anthology = acl_anthology.with_cache_path(cache_path);
+        }
+        if let Some(hours) =
+            env_var("ACL_ANTHOLOGY_REFRESH_HOURS").and_then(|h| h.parse::<u64>().ok())
+        {
+            acl_anthology =
+                acl_anthology.with_refresh_interval(Duration::from_secs(hours * 60 * 60));
+        }
+        // Downloading and indexing the export takes a while, so it happens off
+        // the command path
+        acl_anthology.spawn_refresh();
+
+        let mut collectors: Vec<Box<dyn Collector>> = vec![
+            Box::new(ArxivCollector::new().with_default_query(default_query)),
+            Box::new(HuggingFacePapersCollector::new()),
+            Box::new(semantic_scholar),
+            Box::new(openalex),
+            Box::new(crossref),
+            Box::new(pubmed),
+            Box::new(EuropePmcCollector::new()),
+            Box::new(core),
+            Box::new(BioRxivCollector::biorxiv()),
+            Box::new(BioRxivCollector::medrxiv()),
+            Box::new(zenodo),
+            Box::new(InspireHepCollector::new()),
+            Box::new(HackerNewsCollector::new()),
+            Box::new(LobstersCollector::new()),
+            Box::new(mastodon),
+            Box::new(DevToCollector::new()),
+            Box::new(github),
+            Box::new(stackexchange),
+            Box::new(IetfCollector::new()),
+            Box::new(OpenReviewCollector::new()),
+            Box::new(DblpCollector::new()),
+            Box::new(acl_anthology),
             Box::new(ExampleArticleCollector::new()),
         ];
 
+        // ADS has no anonymous access, so it is only enabled with a token
+        if let Some(token) = env_var("ADS_API_TOKEN") {
+            let mut ads = AdsCollector::new(token);
+            if let Some(base_url) = env_var("ADS_API_URL") {
+                ads = ads.with_base_url(base_url);
+            }
+            collectors.push(Box::new(ads));
+        } else {
+            tracing::info!("ADS_API_TOKEN not set, NASA ADS collector disabled");
+        }
+
+        // Reddit blocks generic clients, so it is only enabled with a User-Agent
+        if let Some(user_agent) = env_var("REDDIT_USER_AGENT") {
+            let mut reddit = RedditCollector::new(user_agent);
+            if let Some(subreddits) = env_var("REDDIT_SUBREDDITS") {
+                reddit = reddit.with_subreddits(
+                    subreddits
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|sub| !sub.is_empty())
+                        .map(String::from)
+                        .collect(),
+                );
+            }
+            if let Some(time_window) = env_var("REDDIT_TIME_WINDOW") {
+                reddit = reddit.with_time_window(time_window);
+            }
+            collectors.push(Box::new(reddit));
+        } else {
+            tracing::info!("REDDIT_USER_AGENT not set, Reddit collector disabled");
+        }
+
+        // Bluesky search needs a logged-in session
+        match (
+            env_var("BLUESKY_IDENTIFIER"),
+            env_var("BLUESKY_APP_PASSWORD"),
+        ) {
+            (Some(identifier), Some(app_password)) => {
+                let mut bluesky = BlueskyCollector::new(identifier, app_password);
+                if let Some(base_url) = env_var("BLUESKY_PDS_URL") {
+                    bluesky = bluesky.with_base_url(base_url);
+                }
+                collectors.push(Box::new(bluesky));
+            }
+            _ => tracing::info!(
+                "BLUESKY_IDENTIFIER or BLUESKY_APP_PASSWORD not set, Bluesky collector disabled"
+            ),
+        }
+
+        if let Some(feeds) = env_var("FEEDS") {
+            for feed in parse_feeds(&feeds) {
+                tracing::info!("Registered feed collector: {}", feed.name());
+                collectors.push(Box::new(feed));
+            }
+        }
+
+        if let Some(path) = env_var("SCRAPERS_FILE") {
+            for scraper in load_scrapers(&path) {
+                tracing::info!("Registered scrape collector: {}", scraper.name());
+                collectors.push(Box::new(scraper));
+            }
+        }
+
         Self {
-            collectors: Arc::new(Mutex::new(collectors)),
-            default_query,
+            collectors: Arc::new(collectors),
             default_max_results,
         }
     }
@@ -44,8 +237,7 @@ impl Bot {
             .options
             .iter()
             .find(|opt| opt.name == "query")
-            .and_then(|opt| opt.value.as_str())
-            .unwrap_or(&self.default_query);
+            .and_then(|opt| opt.value.as_str());
 
         let max_results = command
             .data
@@ -61,32 +253,18 @@ impl Bot {
             return;
         }
 
-        let collectors = self.collectors.lock().await;
-        let mut all_articles = Vec::new();
+        let collectors = &self.collectors;
+        let all_articles;
 
         if source == "all" {
-            for collector in collectors.iter() {
-                match collector.collect(query, max_results).await {
-                    Ok(articles) => {
-                        tracing::info!(
-                            "Collected {} articles from {}",
-                            articles.len(),
-                            collector.name()
-                        );
-                        all_articles.extend(articles);
-                    }
-                    Err(e) => {
-                        tracing::error!("Error collecting from {}: {}", collector.name(), e);
-                    }
-                }
-            }
+            all_articles = collect_all(collectors, query, max_results).await;
         } else {
             let collector = collectors
                 .iter()
-                .find(|c| c.name().to_lowercase() == source.to_lowercase());
+                .find(|c| source_key(c.name()) == source_key(source));
 
             if let Some(collector) = collector {
-                match collector.collect(query, max_results).await {
+                match collect_from(collector.as_ref(), query, max_results).await {
                     Ok(articles) => {
                         tracing::info!(
                             "Collected {} articles from {}",
@@ -129,28 +307,66 @@ impl Bot {
     }
 
     async fn handle_sources_command(&self, ctx: &Context, command: &CommandInteraction) {
-        let collectors = self.collectors.lock().await;
+        let collectors = &self.collectors;
         let mut response = "📚 **Available Sources:**\n\n".to_string();
 
         for collector in collectors.iter() {
             response.push_str(&format!(
-                "• **{}**: {}\n",
+                "• **{}** (`{}`): {}\n",
                 collector.name(),
+                source_key(collector.name()),
                 collector.description()
             ));
         }
 
-        let data = CreateInteractionResponseMessage::new().content(response);
+        // The list outgrows a single message, so the rest goes in follow-ups
+        let mut messages = split_message(&response).into_iter();
+        let data =
+            CreateInteractionResponseMessage::new().content(messages.next().unwrap_or_default());
         let builder = CreateInteractionResponse::Message(data);
 
         if let Err(why) = command.create_response(&ctx.http, builder).await {
             tracing::error!("Cannot respond to slash command: {}", why);
+            return;
+        }
+        for message in messages {
+            let followup = CreateInteractionResponseFollowup::new().content(message);
+            if let Err(why) = command.create_followup(&ctx.http, followup).await {
+                tracing::error!("Cannot send follow-up message: {}", why);
+            }
+        }
+    }
+
+    async fn handle_collect_autocomplete(&self, ctx: &Context, command: &CommandInteraction) {
+        let typed = command
+            .data
+            .autocomplete()
+            .map(|opt| source_key(opt.value))
+            .unwrap_or_default();
+
+        let collectors = &self.collectors;
+        let mut response = CreateAutocompleteResponse::new();
+
+        if "all".contains(&typed) {
+            response = response.add_string_choice("All Sources", "all");
+        }
+        // Discord shows at most 25 suggestions
+        for collector in collectors
+            .iter()
+            .filter(|c| source_key(c.name()).contains(&typed))
+            .take(24)
+        {
+            response = response.add_string_choice(collector.name(), source_key(collector.name()));
+        }
+
+        let builder = CreateInteractionResponse::Autocomplete(response);
+        if let Err(why) = command.create_response(&ctx.http, builder).await {
+            tracing::error!("Cannot respond to autocomplete: {}", why);
         }
     }
 
     async fn handle_schedule_command(&self, ctx: &Context, command: &CommandInteraction) {
-        let schedule =
-            std::env::var("COLLECTION_SCHEDULE").unwrap_or_else(|_| "0 0 9 * * *".to_string());
+        let schedule = env_var("COLLECTION_SCHEDULE").unwrap_or_else(|| "0 0 9 * * *".to_string());
 
         let response = format!(
             "📅 **Collection Schedule:**\n\nCron: `{}`\n\nThe bot will automatically collect articles based on this schedule.",
@@ -178,16 +394,29 @@ impl Bot {
 
         for (i, article) in articles.iter().take(5).enumerate() {
             response.push_str(&format!("**{}. {}**\n", i + 1, article.title));
-            response.push_str(&format!("👤 Authors: {}\n", article.authors.join(", ")));
+            if !article.authors.is_empty() {
+                response.push_str(&format!("👤 Authors: {}\n", article.authors.join(", ")));
+            }
             response.push_str(&format!("📅 Published: {}\n", article.published_date));
             response.push_str(&format!("🔗 URL: {}\n", article.url));
 
-            let summary = if article.summary.len() > 200 {
-                format!("{}...", &article.summary[..200])
-            } else {
-                article.summary.clone()
-            };
-            response.push_str(&format!("📝 Summary: {}\n\n", summary));
+            if !article.metadata.is_empty() {
+                let details: Vec<String> = article
+                    .metadata
+                    .iter()
+                    .map(|(key, value)| format!("{}: {}", key, value))
+                    .collect();
+                response.push_str(&format!("ℹ️ {}\n", details.join(" · ")));
+            }
+
+            if !article.summary.is_empty() {
+                let summary = match article.summary.char_indices().nth(200) {
+                    Some((end, _)) => format!("{}...", &article.summary[..end]),
+                    None => article.summary.clone(),
+                };
+                response.push_str(&format!("📝 Summary: {}\n", summary));
+            }
+            response.push('\n');
         }
 
         if articles.len() > 5 {
@@ -196,7 +425,11 @@ impl Bot {
 
         // Discord message limit is 2000 characters
         if response.len() > 2000 {
-            response.truncate(1997);
+            let mut end = 1997;
+            while !response.is_char_boundary(end) {
+                end -= 1;
+            }
+            response.truncate(end);
             response.push_str("...");
         }
 
@@ -207,27 +440,7 @@ impl Bot {
     pub async fn periodic_collection(&self, ctx: Context, channel_id: u64) {
         tracing::info!("Running periodic collection");
 
-        let collectors = self.collectors.lock().await;
-        let mut all_articles = Vec::new();
-
-        for collector in collectors.iter() {
-            match collector
-                .collect(&self.default_query, self.default_max_results)
-                .await
-            {
-                Ok(articles) => {
-                    tracing::info!(
-                        "Periodic collection: {} articles from {}",
-                        articles.len(),
-                        collector.name()
-                    );
-                    all_articles.extend(articles);
-                }
-                Err(e) => {
-                    tracing::error!("Periodic collection error from {}: {}", collector.name(), e);
-                }
-            }
-        }
+        let all_articles = collect_all(&self.collectors, None, self.default_max_results).await;
 
         if !all_articles.is_empty() {
             let response = self.format_articles_response(&all_articles, "scheduled collection");
@@ -240,6 +453,138 @@ impl Bot {
     }
 }
 
+/// Reads an optional setting, treating empty values (as in `.env.example`) as unset
+pub(crate) fn env_var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
+
+/// Runs a collector, falling back to its default query when none is given
+///
+/// Paginating collectors make several requests, so the whole run is bounded
+/// by `COLLECTOR_DEADLINE` on top of the per-request timeout.
+async fn collect_from(
+    collector: &dyn Collector,
+    query: Option<&str>,
+    max_results: usize,
+) -> CollectorResult<Vec<Article>> {
+    let query = query.unwrap_or(collector.default_query());
+    tokio::time::timeout(COLLECTOR_DEADLINE, collector.collect(query, max_results))
+        .await
+        .map_err(|_| format!("{} did not finish in time", collector.name()))?
+}
+
+/// Runs every collector, `CONCURRENT_COLLECTORS` at a time, keeping what
+/// succeeds in collector order
+async fn collect_all(
+    collectors: &[Box<dyn Collector>],
+    query: Option<&str>,
+    max_results: usize,
+) -> Vec<Article> {
+    // Boxed so the stream stays `Send` inside serenity's async handlers
+    let runs: Vec<BoxFuture<'_, _>> = collectors
+        .iter()
+        .map(|collector| {
+            let run = async move {
+                let result = collect_from(collector.as_ref(), query, max_results).await;
+                (collector.name(), result)
+            };
+            run.boxed()
+        })
+        .collect();
+    let results: Vec<_> = stream::iter(runs)
+        .buffered(CONCURRENT_COLLECTORS)
+        .collect()
+        .await;
+
+    let mut all_articles = Vec::new();
+    for (name, result) in results {
+        match result {
+            Ok(articles) => {
+                tracing::info!("Collected {} articles from {}", articles.len(), name);
+                all_articles.extend(articles);
+            }
+            Err(e) => {
+                tracing::error!("Error collecting from {}: {}", name, e);
+            }
+        }
+    }
+    all_articles
+}
+
+/// Normalizes a source name so `semanticscholar` matches "Semantic Scholar"
+fn source_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Splits text at line breaks into chunks within Discord's 2000 character limit
+fn split_message(text: &str) -> Vec<String> {
+    const LIMIT: usize = 2000;
+    let mut messages = Vec::new();
+    let mut current = String::new();
+
+    for line in text.lines() {
+        if !current.is_empty() && current.chars().count() + line.chars().count() + 1 > LIMIT {
+            messages.push(std::mem::take(&mut current));
+        }
+        current.push_str(line);
+        current.push('\n');
+    }
+    messages.push(current);
+
+    messages
+}
+
+/// Parses `FEEDS`, e.g. `DeepMind=https://a/rss.xml;Lab Blog=https://b/atom,https://c/feed.json`
+fn parse_feeds(spec: &str) -> Vec<FeedCollector> {
+    spec.split(';')
+        .filter_map(|entry| {
+            let (name, urls) = entry.split_once('=')?;
+            let urls: Vec<String> = urls
+                .split(',')
+                .map(str::trim)
+                .filter(|url| !url.is_empty())
+                .map(String::from)
+                .collect();
+            let name = name.trim();
+            if name.is_empty() || urls.is_empty() {
+                tracing::warn!("Ignoring malformed FEEDS entry: {}", entry);
+                return None;
+            }
+            Some(FeedCollector::new(name, urls))
+        })
+        .collect()
+}
+
+/// Reads `SCRAPERS_FILE`, a JSON array of scraper definitions (see `ScrapeConfig`)
+fn load_scrapers(path: &str) -> Vec<ScrapeCollector> {
+    let configs: Vec<ScrapeConfig> = match std::fs::read_to_string(path)
+        .map_err(|e| e.to_string())
+        .and_then(|json| serde_json::from_str(&json).map_err(|e| e.to_string()))
+    {
+        Ok(configs) => configs,
+        Err(e) => {
+            tracing::error!("Failed to load SCRAPERS_FILE {}: {}", path, e);
+            return Vec::new();
+        }
+    };
+
+    configs
+        .into_iter()
+        .filter_map(|config| match ScrapeCollector::new(config) {
+            Ok(scraper) => Some(scraper),
+            Err(e) => {
+                tracing::warn!("Ignoring scraper: {}", e);
+                None
+            }
+        })
+        .collect()
+}
+
 #[async_trait]
 impl EventHandler for Bot {
     async fn ready(&self, ctx: Context, ready: Ready) {
@@ -251,7 +596,7 @@ impl EventHandler for Bot {
             crate::commands::schedule_command(),
         ];
 
-        if let Ok(guild_id_str) = std::env::var("GUILD_ID") {
+        if let Some(guild_id_str) = env_var("GUILD_ID") {
             if let Ok(guild_id) = guild_id_str.parse::<u64>() {
                 let guild_id = serenity::model::id::GuildId::new(guild_id);
                 if let Err(why) = guild_id.set_commands(&ctx.http, commands).await {
@@ -276,17 +621,187 @@ impl EventHandler for Bot {
         ctx: Context,
         interaction: serenity::model::application::Interaction,
     ) {
-        if let serenity::model::application::Interaction::Command(command) = interaction {
-            tracing::info!("Received command: {}", command.data.name);
-
-            match command.data.name.as_str() {
-                "collect" => self.handle_collect_command(&ctx, &command).await,
-                "sources" => self.handle_sources_command(&ctx, &command).await,
-                "schedule" => self.handle_schedule_command(&ctx, &command).await,
-                _ => {
-                    tracing::warn!("Unknown command: {}", command.data.name);
+        match interaction {
+            serenity::model::application::Interaction::Command(command) => {
+                tracing::info!("Received command: {}", command.data.name);
+
+                match command.data.name.as_str() {
+                    "collect" => self.handle_collect_command(&ctx, &command).await,
+                    "sources" => self.handle_sources_command(&ctx, &command).await,
+                    "schedule" => self.handle_schedule_command(&ctx, &command).await,
+                    _ => {
+                        tracing::warn!("Unknown command: {}", command.data.name);
+                    }
                 }
             }
+            serenity::model::application::Interaction::Autocomplete(command)
+                if command.data.name == "collect" =>
+            {
+                self.handle_collect_autocomplete(&ctx, &command).await;
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, Request, ResponseTemplate};
+
+    /// Matches requests carrying a non-empty `name` parameter, since the
+    /// search APIs reject an empty search
+    fn non_empty_param(name: &'static str) -> impl Fn(&Request) -> bool {
+        move |request: &Request| {
+            request
+                .url
+                .query_pairs()
+                .any(|(key, value)| key == name && !value.trim().is_empty())
+        }
+    }
+
+    #[tokio::test]
+    async fn collect_without_query_searches_the_default() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/graph/v1/paper/search"))
+            .and(non_empty_param("query"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"data": []})))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/search/repositories"))
+            .and(non_empty_param("q"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"items": []})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/api/v1/timelines/tag/machinelearning"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([])))
+            .expect(1)
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/xrpc/com.atproto.server.createSession"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"accessJwt": "jwt"})),
+            )
+            .mount(&server)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/xrpc/app.bsky.feed.searchPosts"))
+            .and(non_empty_param("q"))
+            .respond_with(
+                ResponseTemplate::new(200).set_body_json(serde_json::json!({"posts": []})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let collectors: Vec<Box<dyn Collector>> = vec![
+            Box::new(SemanticScholarCollector::new().with_base_url(server.uri())),
+            Box::new(GitHubCollector::new().with_base_url(server.uri())),
+            Box::new(MastodonCollector::new().with_base_url(server.uri())),
+            Box::new(
+                BlueskyCollector::new("me.bsky.social", "app-password").with_base_url(server.uri()),
+            ),
+        ];
+        for collector in &collectors {
+            let result = collect_from(collector.as_ref(), None, 5).await;
+            assert!(result.is_ok(), "{}: {:?}", collector.name(), result.err());
         }
     }
+
+    /// Answers after `delay` with a single article named after itself
+    struct SlowCollector {
+        name: String,
+        delay: Duration,
+    }
+
+    #[async_trait]
+    impl Collector for SlowCollector {
+        fn name(&self) -> &str {
+            &self.name
+        }
+
+        fn description(&self) -> &str {
+            "Waits before answering"
+        }
+
+        async fn collect(
+            &self,
+            _query: &str,
+            _max_results: usize,
+        ) -> CollectorResult<Vec<Article>> {
+            tokio::time::sleep(self.delay).await;
+            Ok(vec![Article {
+                title: self.name.clone(),
+                ..Default::default()
+            }])
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn collect_all_runs_collectors_concurrently_within_the_deadline() {
+        let mut collectors: Vec<Box<dyn Collector>> = (0..CONCURRENT_COLLECTORS)
+            .map(|i| {
+                Box::new(SlowCollector {
+                    name: format!("Source {}", i),
+                    delay: Duration::from_secs(20),
+                }) as Box<dyn Collector>
+            })
+            .collect();
+        collectors.push(Box::new(SlowCollector {
+            name: "Stalled".to_string(),
+            delay: Duration::from_secs(60 * 60),
+        }));
+
+        let started = tokio::time::Instant::now();
+        let articles = collect_all(&collectors, None, 5).await;
+
+        let titles: Vec<String> = articles.into_iter().map(|a| a.title).collect();
+        let expected: Vec<String> = (0..CONCURRENT_COLLECTORS)
+            .map(|i| format!("Source {}", i))
+            .collect();
+        assert_eq!(titles, expected);
+        // One batch of 20 seconds, then the stalled collector is cut off
+        assert_eq!(
+            started.elapsed(),
+            Duration::from_secs(20) + COLLECTOR_DEADLINE
+        );
+    }
+
+    #[test]
+    fn search_only_collectors_have_a_default_query() {
+        let collectors: Vec<Box<dyn Collector>> = vec![
+            Box::new(SemanticScholarCollector::new()),
+            Box::new(CrossrefCollector::new()),
+            Box::new(PubMedCollector::new()),
+            Box::new(EuropePmcCollector::new()),
+            Box::new(CoreCollector::new()),
+            Box::new(OpenReviewCollector::new()),
+            Box::new(DblpCollector::new()),
+            Box::new(AdsCollector::new("token")),
+            Box::new(BlueskyCollector::new("me.bsky.social", "app-password")),
+            Box::new(GitHubCollector::new()),
+            Box::new(MastodonCollector::new()),
+        ];
+        for collector in &collectors {
+            assert!(
+                !collector.default_query().is_empty(),
+                "{}",
+                collector.name()
+            );
+        }
+
+        let github = GitHubCollector::new().with_watched_repos(vec!["rust-lang/rust".to_string()]);
+        assert_eq!(github.default_query(), "releases");
+        let mastodon = MastodonCollector::new().with_hashtags(vec!["rust".to_string()]);
+        assert_eq!(mastodon.default_query(), "");
+    }
 }
@@ -0,0 +1,631 @@
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+use flate2::read::GzDecoder;
+use unicode_normalization::UnicodeNormalization;
+
+use super::{Article, Collector, CollectorResult};
+
+const DEFAULT_DATA_URL: &str = "https://aclanthology.org/anthology+abstracts.bib.gz";
+const DEFAULT_CACHE_PATH: &str = "data/anthology+abstracts.bib.gz";
+/// The anthology export is regenerated roughly weekly
+const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+/// How often the background task checks whether the index is due a refresh
+const REFRESH_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+/// The export is tens of megabytes, far beyond the usual request timeout
+const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Collects NLP papers from a local index of the ACL Anthology
+///
+/// The anthology has no search API, so the collector downloads its full
+/// BibTeX export, caches it on disk and answers queries offline. A background
+/// task started by `spawn_refresh` rebuilds the index from a fresh download
+/// once it is older than the refresh interval; queries always use the
+/// current index. Query words are keywords over title, authors and abstract;
+/// `venue:<name>` (e.g. `venue:emnlp`) and `year:<yyyy>` narrow the results.
+#[derive(Clone)]
+pub struct AclAnthologyCollector {
+    client: reqwest::Client,
+    data_url: String,
+    cache_path: PathBuf,
+    refresh_interval: Duration,
+    index: Arc<RwLock<Option<Arc<Index>>>>,
+}
+
+impl AclAnthologyCollector {
+    pub fn new() -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(DOWNLOAD_TIMEOUT)
+                .build()
+                .expect("failed to build HTTP client"),
+            data_url: DEFAULT_DATA_URL.to_string(),
+            cache_path: PathBuf::from(DEFAULT_CACHE_PATH),
+            refresh_interval: DEFAULT_REFRESH_INTERVAL,
+            index: Arc::new(RwLock::new(None)),
+        }
+    }
+
+    /// Overrides the BibTeX export URL (plain or gzipped)
+    pub fn with_data_url(mut self, data_url: impl Into<String>) -> Self {
+        self.data_url = data_url.into();
+        self
+    }
+
+    /// Sets where the downloaded export is cached between restarts
+    pub fn with_cache_path(mut self, cache_path: impl Into<PathBuf>) -> Self {
+        self.cache_path = cache_path.into();
+        self
+    }
+
+    /// Sets how old the cached export may get before it is downloaded again
+    pub fn with_refresh_interval(mut self, refresh_interval: Duration) -> Self {
+        self.refresh_interval = refresh_interval;
+        self
+    }
+
+    /// Starts a task that builds the index now and keeps it refreshed
+    pub fn spawn_refresh(&self) -> tokio::task::JoinHandle<()> {
+        let collector = self.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(REFRESH_CHECK_INTERVAL);
+            loop {
+                ticker.tick().await;
+                if let Err(e) = collector.refresh().await {
+                    tracing::error!("Failed to refresh the ACL Anthology index: {}", e);
+                }
+            }
+        })
+    }
+
+    /// Builds the index if it is missing or stale, from a fresh cache on disk
+    /// or else a new download; on failure the previous index stays in use,
+    /// falling back to a stale cache if there is none
+    pub async fn refresh(&self) -> CollectorResult<()> {
+        let current = self.current_index();
+        if current
+            .as_ref()
+            .is_some_and(|index| !self.is_stale(index.built_at))
+        {
+            return Ok(());
+        }
+
+        // A fresh cache from a previous run avoids downloading again
+        if current.is_none() {
+            if let Some(modified) = cache_modified(&self.cache_path).await {
+                if !self.is_stale(modified) {
+                    match self.load_cache(modified).await {
+                        Ok(index) => {
+                            self.set_index(index);
+                            return Ok(());
+                        }
+                        Err(e) => tracing::warn!(
+                            "Ignoring unreadable ACL Anthology cache {:?}: {}",
+                            self.cache_path,
+                            e
+                        ),
+                    }
+                }
+            }
+        }
+
+        match self.download().await {
+            Ok(index) => {
+                self.set_index(index);
+                Ok(())
+            }
+            Err(e) if current.is_none() => {
+                let modified = cache_modified(&self.cache_path).await.ok_or(e)?;
+                tracing::warn!("Using stale ACL Anthology cache {:?}", self.cache_path);
+                let index = self.load_cache(modified).await?;
+                self.set_index(index);
+                Ok(())
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    fn current_index(&self) -> Option<Arc<Index>> {
+        self.index
+            .read()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .clone()
+    }
+
+    fn set_index(&self, index: Index) {
+        *self
+            .index
+            .write()
+            .unwrap_or_else(|poisoned| poisoned.into_inner()) = Some(Arc::new(index));
+    }
+
+    fn is_stale(&self, built_at: SystemTime) -> bool {
+        built_at
+            .elapsed()
+            .is_ok_and(|age| age >= self.refresh_interval)
+    }
+
+    async fn download(&self) -> CollectorResult<Index> {
+        tracing::info!("Downloading ACL Anthology export: {}", self.data_url);
+
+        let response = self
+            .client
+            .get(&self.data_url)
+            .send()
+            .await?
+            .error_for_status()?;
+        let data = Arc::new(response.bytes().await?.to_vec());
+
+        // Only an export that indexes cleanly replaces the cache
+        let index = build_index(data.clone(), SystemTime::now()).await?;
+        if let Err(e) = self.write_cache(&data).await {
+            tracing::error!(
+                "Failed to write ACL Anthology cache {:?}: {}",
+                self.cache_path,
+                e
+            );
+        }
+        Ok(index)
+    }
+
+    /// Writes through a temporary file, so a crash never leaves a truncated
+    /// cache behind
+    async fn write_cache(&self, data: &[u8]) -> std::io::Result<()> {
+        if let Some(parent) = self.cache_path.parent() {
+            tokio::fs::create_dir_all(parent).await?;
+        }
+        let mut partial = self.cache_path.clone().into_os_string();
+        partial.push(".partial");
+        tokio::fs::write(&partial, data).await?;
+        tokio::fs::rename(&partial, &self.cache_path).await
+    }
+
+    async fn load_cache(&self, modified: SystemTime) -> CollectorResult<Index> {
+        tracing::info!("Loading ACL Anthology cache {:?}", self.cache_path);
+
+        let data = tokio::fs::read(&self.cache_path).await?;
+        build_index(Arc::new(data), modified).await
+    }
+}
+
+impl Default for AclAnthologyCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+async fn cache_modified(path: &Path) -> Option<SystemTime> {
+    tokio::fs::metadata(path).await.ok()?.modified().ok()
+}
+
+/// Decompresses and parses the export off the async runtime, it is large
+async fn build_index(data: Arc<Vec<u8>>, built_at: SystemTime) -> CollectorResult<Index> {
+    let papers = tokio::task::spawn_blocking(move || -> CollectorResult<Vec<Paper>> {
+        let text = decode(&data)?;
+        Ok(parse_bibtex(&text))
+    })
+    .await??;
+    if papers.is_empty() {
+        return Err("The ACL Anthology export contains no papers".into());
+    }
+
+    tracing::info!("Indexed {} ACL Anthology papers", papers.len());
+    Ok(Index { papers, built_at })
+}
+
+fn decode(data: &[u8]) -> CollectorResult<String> {
+    if data.starts_with(&[0x1f, 0x8b]) {
+        let mut text = String::new();
+        GzDecoder::new(data).read_to_string(&mut text)?;
+        Ok(text)
+    } else {
+        Ok(std::str::from_utf8(data)?.to_string())
+    }
+}
+
+struct Index {
+    papers: Vec<Paper>,
+    built_at: SystemTime,
+}
+
+#[derive(Debug)]
+struct Paper {
+    /// Anthology id, e.g. `2023.emnlp-main.1` or `P19-1001`
+    id: String,
+    title: String,
+    authors: Vec<String>,
+    venue: Option<String>,
+    year: Option<String>,
+    month: Option<String>,
+    url: String,
+    doi: Option<String>,
+    abstract_text: Option<String>,
+}
+
+/// Venue letters of pre-2020 anthology ids (`P19-1001` is ACL 2019)
+const LEGACY_VENUES: &[(char, &str)] = &[
+    ('A', "anlp"),
+    ('C', "coling"),
+    ('D', "emnlp"),
+    ('E', "eacl"),
+    ('H', "hlt"),
+    ('I', "ijcnlp"),
+    ('J', "cl"),
+    ('K', "conll"),
+    ('L', "lrec"),
+    ('N', "naacl"),
+    ('P', "acl"),
+    ('Q', "tacl"),
+    ('S', "semeval"),
+    ('W', "ws"),
+];
+
+impl Paper {
+    /// Venue slugs of the anthology id: `2023.findings-emnlp.12` yields
+    /// `findings` and `emnlp`, `D19-1001` yields `emnlp`
+    fn venue_slugs(&self) -> Vec<String> {
+        match self.id.split('.').collect::<Vec<_>>().as_slice() {
+            [_, volume, _] => volume.split('-').map(str::to_lowercase).collect(),
+            _ => self
+                .id
+                .chars()
+                .next()
+                .and_then(|letter| LEGACY_VENUES.iter().find(|(l, _)| *l == letter))
+                .map(|(_, venue)| vec![venue.to_string()])
+                .unwrap_or_default(),
+        }
+    }
+
+    fn matches(&self, query: &Query) -> bool {
+        if let Some(year) = &query.year {
+            if self.year.as_ref() != Some(year) {
+                return false;
+            }
+        }
+
+        if !query.venues.is_empty() {
+            let slugs = self.venue_slugs();
+            if !query.venues.iter().any(|venue| slugs.contains(venue)) {
+                return false;
+            }
+        }
+
+        if query.keywords.is_empty() {
+            return true;
+        }
+        let text = format!(
+            "{} {} {}",
+            self.title,
+            self.authors.join(" "),
+            self.abstract_text.as_deref().unwrap_or_default()
+        )
+        .to_lowercase();
+        query.keywords.iter().all(|keyword| text.contains(keyword))
+    }
+
+    fn to_article(&self) -> Article {
+        let published_date = match (&self.year, &self.month) {
+            (Some(year), Some(month)) => format!("{}-{}", year, month),
+            (Some(year), None) => year.clone(),
+            _ => String::new(),
+        };
+
+        let mut article = Article {
+            title: self.title.clone(),
+            authors: self.authors.clone(),
+            url: self.url.clone(),
+            published_date,
+            summary: self.abstract_text.clone().unwrap_or_default(),
+            source: "ACL Anthology".to_string(),
+            ..Default::default()
+        };
+
+        article
+            .metadata
+            .insert("anthology".to_string(), self.id.clone());
+        if let Some(venue) = &self.venue {
+            article.metadata.insert("venue".to_string(), venue.clone());
+        }
+        if let Some(doi) = &self.doi {
+            article.metadata.insert("doi".to_string(), doi.clone());
+        }
+
+        article
+    }
+}
+
+#[derive(Debug, Default)]
+struct Query {
+    keywords: Vec<String>,
+    venues: Vec<String>,
+    year: Option<String>,
+}
+
+impl Query {
+    fn parse(query: &str) -> Self {
+        let mut parsed = Self::default();
+        for word in query.split_whitespace() {
+            // Composed like the index, so `müller` matches however it was typed
+            let word = word.nfc().collect::<String>().to_lowercase();
+            if let Some(venue) = word.strip_prefix("venue:") {
+                parsed.venues.push(venue.to_string());
+            } else if let Some(year) = word.strip_prefix("year:") {
+                parsed.year = Some(year.to_string());
+            } else {
+                parsed.keywords.push(word);
+            }
+        }
+        parsed
+    }
+}
+
+/// Parses the entries of a BibTeX file, skipping whole-volume `@proceedings`
+fn parse_bibtex(text: &str) -> Vec<Paper> {
+    let bytes = text.as_bytes();
+    let mut papers = Vec::new();
+    let mut pos = 0;
+
+    while let Some(offset) = text[pos..].find('@') {
+        let start = pos + offset + 1;
+        let Some(open) = text[start..].find('{') else {
+            break;
+        };
+        let entry_type = text[start..start + open].trim().to_lowercase();
+        let body_start = start + open + 1;
+        let Some(body_end) = closing_brace(bytes, body_start) else {
+            break;
+        };
+        pos = body_end + 1;
+
+        if matches!(
+            entry_type.as_str(),
+            "proceedings" | "comment" | "string" | "preamble"
+        ) {
+            continue;
+        }
+        if let Some(paper) = parse_entry(&text[body_start..body_end]) {
+            papers.push(paper);
+        }
+    }
+
+    papers
+}
+
+/// Finds the `}` closing a brace opened just before `start`
+fn closing_brace(bytes: &[u8], start: usize) -> Option<usize> {
+    let mut depth = 1;
+    for (i, &byte) in bytes.iter().enumerate().skip(start) {
+        match byte {
+            b'{' => depth += 1,
+            b'}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn parse_entry(body: &str) -> Option<Paper> {
+    let (key, fields) = body.split_once(',')?;
+    let mut fields = parse_fields(fields);
+
+    let title = fields.remove("title")?;
+    let url = fields.remove("url").unwrap_or_default();
+    let id = url
+        .rsplit('/')
+        .find(|segment| !segment.is_empty())
+        .filter(|_| !url.is_empty())
+        .unwrap_or(key.trim())
+        .to_string();
+
+    Some(Paper {
+        id,
+        title,
+        authors: fields
+            .remove("author")
+            .map(|authors| parse_authors(&authors))
+            .unwrap_or_default(),
+        venue: fields
+            .remove("booktitle")
+            .or_else(|| fields.remove("journal")),
+        year: fields.remove("year"),
+        month: fields
+            .remove("month")
+            .and_then(|month| month_number(&month)),
+        url,
+        doi: fields.remove("doi"),
+        abstract_text: fields.remove("abstract"),
+    })
+}
+
+/// Parses `name = value` pairs, where a value is `{...}`, `"..."`, a bare
+/// word (number or month macro) or a `#` concatenation of those
+fn parse_fields(body: &str) -> HashMap<String, String> {
+    let bytes = body.as_bytes();
+    let mut fields = HashMap::new();
+    let mut pos = 0;
+
+    while let Some(eq) = body[pos..].find('=') {
+        let name = body[pos..pos + eq]
+            .trim_matches(|c: char| c == ',' || c.is_whitespace())
+            .to_lowercase();
+        let mut i = pos + eq + 1;
+        let mut value = String::new();
+
+        loop {
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            match bytes.get(i) {
+                Some(b'{') => {
+                    let end = closing_brace(bytes, i + 1).unwrap_or(bytes.len());
+                    value.push_str(&body[i + 1..end]);
+                    i = end + 1;
+                }
+                Some(b'"') => {
+                    let mut depth = 0;
+                    let mut end = i + 1;
+                    while end < bytes.len() && !(bytes[end] == b'"' && depth == 0) {
+                        match bytes[end] {
+                            b'{' => depth += 1,
+                            b'}' => depth -= 1,
+                            _ => {}
+                        }
+                        end += 1;
+                    }
+                    value.push_str(&body[i + 1..end]);
+                    i = end + 1;
+                }
+                Some(_) => {
+                    let end = body[i..]
+                        .find(|c: char| c == ',' || c == '#' || c.is_whitespace())
+                        .map_or(body.len(), |e| i + e);
+                    value.push_str(&body[i..end]);
+                    i = end;
+                }
+                None => break,
+            }
+            while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+                i += 1;
+            }
+            if bytes.get(i) == Some(&b'#') {
+                i += 1;
+            } else {
+                break;
+            }
+        }
+
+        let value = clean_latex(&value);
+        if !value.is_empty() {
+            fields.insert(name, value);
+        }
+        pos = i.min(body.len());
+    }
+
+    fields
+}
+
+/// Drops case-protecting braces, decodes accent and symbol escapes, and
+/// collapses whitespace
+fn clean_latex(value: &str) -> String {
+    let unbraced = value.replace(['{', '}'], "");
+    let mut text = String::with_capacity(unbraced.len());
+    let mut chars = unbraced.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            text.push(c);
+            continue;
+        }
+        let Some(command) = chars.next() else {
+            break;
+        };
+        match combining_mark(command) {
+            // `\"u`, `\'\i`, `\v c`: the accented letter, then the mark
+            Some(mark) => {
+                while chars.next_if(|c| *c == ' ' || *c == '\\').is_some() {}
+                if let Some(letter) = chars.next() {
+                    text.push(letter);
+                }
+                text.push(mark);
+            }
+            // `\i` and `\j` are dotless letters, everything else (`\&`, `\%`) is literal
+            None => text.push(command),
+        }
+    }
+
+    // Composes letter + mark pairs into single characters (`u` + `¨` → `ü`)
+    text.split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .nfc()
+        .collect()
+}
+
+fn combining_mark(command: char) -> Option<char> {
+    Some(match command {
+        '`' => '\u{300}',
+        '\'' => '\u{301}',
+        '^' => '\u{302}',
+        '~' => '\u{303}',
+        '=' => '\u{304}',
+        'u' => '\u{306}',
+        '.' => '\u{307}',
+        '"' => '\u{308}',
+        'r' => '\u{30a}',
+        'H' => '\u{30b}',
+        'v' => '\u{30c}',
+        'c' => '\u{327}',
+        'k' => '\u{328}',
+        _ => return None,
+    })
+}
+
+/// Turns `Devlin, Jacob and Chang, Ming-Wei` into `["Jacob Devlin", "Ming-Wei Chang"]`
+fn parse_authors(authors: &str) -> Vec<String> {
+    authors
+        .split(" and ")
+        .map(|author| match author.split_once(',') {
+            Some((last, first)) => format!("{} {}", first.trim(), last.trim()),
+            None => author.trim().to_string(),
+        })
+        .filter(|author| !author.is_empty())
+        .collect()
+}
+
+fn month_number(month: &str) -> Option<String> {
+    const MONTHS: [&str; 12] = [
+        "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+    ];
+    let prefix = month.get(..3)?.to_lowercase();
+    MONTHS
+        .iter()
+        .position(|m| *m == prefix)
+        .map(|i| format!("{:02}", i + 1))
+}
+
+#[async_trait]
+impl Collector for AclAnthologyCollector {
+    fn name(&self) -> &str {
+        "ACL Anthology"
+    }
+
+    fn description(&self) -> &str {
+        "Searches a local index of ACL Anthology NLP papers by keyword, venue and year"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let index = self
+            .current_index()
+            .ok_or("The ACL Anthology index is still being built, try again shortly")?;
+        let query = Query::parse(query);
+
+        tracing::info!(
+            "Searching {} ACL Anthology papers ({:?})",
+            index.papers.len(),
+            query
+        );
+
+        let mut papers: Vec<&Paper> = index
+            .papers
+            .iter()
+            .filter(|paper| paper.matches(&query))
+            .collect();
+        // Newest first; the sort is stable so export order breaks ties
+        papers.sort_by(|a, b| b.year.cmp(&a.year));
+
+        Ok(papers
+            .into_iter()
+            .take(max_results)
+            .map(Paper::to_article)
+            .collect())
+    }
+}
@@ -0,0 +1,190 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://api.adsabs.harvard.edu/v1";
+/// ADS caps `rows` at 2000, but `/collect` asks for at most 20 results
+const MAX_ROWS: usize = 100;
+/// Author lists longer than this end in "et al."
+const MAX_LISTED_AUTHORS: usize = 10;
+const FIELDS: &str = "bibcode,title,author,pubdate,pub,doi,identifier,citation_count,abstract";
+
+/// Collects astronomy and astrophysics literature from NASA ADS
+///
+/// The query uses ADS search syntax (`author:"^hawking" year:1974`,
+/// `abs:"dark energy" property:refereed`). Results are newest first;
+/// `sort:citations` orders them by citation count instead. The API needs a
+/// personal token from the ADS user settings.
+pub struct AdsCollector {
+    client: reqwest::Client,
+    base_url: String,
+    token: String,
+}
+
+impl AdsCollector {
+    pub fn new(token: impl Into<String>) -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            token: token.into(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    response: SearchResult,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResult {
+    #[serde(default)]
+    docs: Vec<Doc>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Doc {
+    bibcode: String,
+    #[serde(default)]
+    title: Vec<String>,
+    #[serde(default)]
+    author: Vec<String>,
+    /// `YYYY-MM-00` when the day is unknown
+    pubdate: Option<String>,
+    #[serde(rename = "pub")]
+    publication: Option<String>,
+    #[serde(default)]
+    doi: Vec<String>,
+    #[serde(default)]
+    identifier: Vec<String>,
+    citation_count: Option<u64>,
+    #[serde(rename = "abstract")]
+    abstract_text: Option<String>,
+}
+
+impl Doc {
+    fn into_article(self) -> Article {
+        let mut authors: Vec<String> = self
+            .author
+            .iter()
+            .take(MAX_LISTED_AUTHORS)
+            .map(|name| match name.split_once(", ") {
+                Some((family, given)) => format!("{} {}", given, family),
+                None => name.clone(),
+            })
+            .collect();
+        if self.author.len() > MAX_LISTED_AUTHORS {
+            authors.push("et al.".to_string());
+        }
+
+        let mut article = Article {
+            title: self.title.into_iter().next().unwrap_or_default(),
+            authors,
+            url: format!(
+                "https://ui.adsabs.harvard.edu/abs/{}/abstract",
+                self.bibcode
+            ),
+            published_date: self
+                .pubdate
+                .map(|date| date.trim_end_matches("-00").to_string())
+                .unwrap_or_default(),
+            summary: self
+                .abstract_text
+                .unwrap_or_default()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+            source: "NASA ADS".to_string(),
+            ..Default::default()
+        };
+
+        article.metadata.insert("bibcode".to_string(), self.bibcode);
+        if let Some(doi) = self.doi.into_iter().next() {
+            article.metadata.insert("doi".to_string(), doi);
+        }
+        if let Some(citations) = self.citation_count {
+            article
+                .metadata
+                .insert("citations".to_string(), citations.to_string());
+        }
+        if let Some(arxiv) = self
+            .identifier
+            .iter()
+            .find_map(|id| id.strip_prefix("arXiv:"))
+        {
+            article
+                .metadata
+                .insert("arxiv".to_string(), arxiv.to_string());
+        }
+        if let Some(publication) = self.publication {
+            article.metadata.insert("journal".to_string(), publication);
+        }
+
+        article
+    }
+}
+
+#[async_trait]
+impl Collector for AdsCollector {
+    fn name(&self) -> &str {
+        "NASA ADS"
+    }
+
+    fn description(&self) -> &str {
+        "Searches NASA ADS astrophysics literature with ADS syntax, with citation counts"
+    }
+
+    fn default_query(&self) -> &str {
+        "collection:astronomy"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let mut sort = "date desc";
+        let mut words = Vec::new();
+        for word in query.split_whitespace() {
+            match word {
+                "sort:citations" => sort = "citation_count desc",
+                "sort:date" => sort = "date desc",
+                _ => words.push(word),
+            }
+        }
+        let search = words.join(" ");
+
+        let url = format!("{}/search/query", self.base_url);
+        let rows = max_results.clamp(1, MAX_ROWS).to_string();
+
+        tracing::info!("Fetching from NASA ADS: {} (query: {})", url, search);
+
+        let response = self
+            .client
+            .get(&url)
+            .bearer_auth(&self.token)
+            .query(&[
+                ("q", search.as_str()),
+                ("fl", FIELDS),
+                ("sort", sort),
+                ("rows", rows.as_str()),
+            ])
+            .send()
+            .await?
+            .error_for_status()?;
+        let search: SearchResponse = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse NASA ADS response: {}", e);
+            format!("Failed to parse NASA ADS response: {}", e)
+        })?;
+
+        Ok(search
+            .response
+            .docs
+            .into_iter()
+            .take(max_results)
+            .map(Doc::into_article)
+            .collect())
+    }
+}
@@ -2,18 +2,32 @@ use async_trait::async_trait;
 use quick_xml::de::from_str;
 use serde::Deserialize;
 
-use super::{Article, Collector, CollectorResult};
+use super::{http_client, Article, Collector, CollectorResult};
 
 pub struct ArxivCollector {
     client: reqwest::Client,
+    default_query: String,
 }
 
 impl ArxivCollector {
     pub fn new() -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: http_client(),
+            default_query: "cat:cs.AI".to_string(),
         }
     }
+
+    /// Sets the query used when none is given (default: `cat:cs.AI`)
+    pub fn with_default_query(mut self, default_query: impl Into<String>) -> Self {
+        self.default_query = default_query.into();
+        self
+    }
+}
+
+impl Default for ArxivCollector {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[derive(Debug, Deserialize)]
@@ -47,6 +61,10 @@ impl Collector for ArxivCollector {
         "Collects academic papers from arXiv.org"
     }
 
+    fn default_query(&self) -> &str {
+        &self.default_query
+    }
+
     async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
         let url = format!(
             "http://export.arxiv.org/api/query?search_query={}&start=0&max_results={}",
@@ -75,6 +93,7 @@ impl Collector for ArxivCollector {
                 published_date: entry.published,
                 summary: entry.summary.trim().replace('\n', " "),
                 source: "Arxiv".to_string(),
+                ..Default::default()
             })
             .collect();
 
@@ -0,0 +1,343 @@
+use async_trait::async_trait;
+use chrono::{Days, NaiveDate, Utc};
+use serde::Deserialize;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://api.biorxiv.org";
+const DEFAULT_INTERVAL: &str = "7d";
+/// Upper bound on pages scanned per collection, each holding up to 100 preprints
+const MAX_PAGES: usize = 100;
+
+/// Collects preprints from the bioRxiv or medRxiv `details` API
+///
+/// The API lists every preprint posted in a date interval, so filtering
+/// happens client-side: `category:<name>` words select subject categories
+/// (underscores stand for spaces, e.g. `category:cell_biology`),
+/// `interval:<range>` overrides the interval (`2024-01-01/2024-01-31`, `30d`)
+/// and the remaining words are keywords that must all appear in the title or
+/// abstract. The interval is scanned a day at a time from its newest day, so
+/// the most recent matches are found first.
+pub struct BioRxivCollector {
+    client: reqwest::Client,
+    base_url: String,
+    server: &'static str,
+    name: &'static str,
+    interval: String,
+}
+
+impl BioRxivCollector {
+    /// Creates a collector for bioRxiv preprints
+    pub fn biorxiv() -> Self {
+        Self::new("biorxiv", "bioRxiv")
+    }
+
+    /// Creates a collector for medRxiv preprints
+    pub fn medrxiv() -> Self {
+        Self::new("medrxiv", "medRxiv")
+    }
+
+    fn new(server: &'static str, name: &'static str) -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            server,
+            name,
+            interval: DEFAULT_INTERVAL.to_string(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the default interval, either `YYYY-MM-DD/YYYY-MM-DD` or `<N>d`
+    pub fn with_interval(mut self, interval: impl Into<String>) -> Self {
+        self.interval = interval.into();
+        self
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DetailsResponse {
+    #[serde(default)]
+    messages: Vec<DetailsMessage>,
+    #[serde(default)]
+    collection: Vec<Preprint>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DetailsMessage {
+    /// Reported as a string by the API
+    total: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Preprint {
+    doi: String,
+    title: String,
+    authors: String,
+    date: String,
+    version: Option<String>,
+    category: String,
+    #[serde(rename = "abstract")]
+    abstract_text: String,
+}
+
+/// Client-side filters parsed from the query
+struct PreprintFilter {
+    categories: Vec<String>,
+    keywords: Vec<String>,
+    interval: Option<String>,
+}
+
+impl PreprintFilter {
+    fn parse(query: &str) -> Self {
+        let mut filter = Self {
+            categories: Vec::new(),
+            keywords: Vec::new(),
+            interval: None,
+        };
+
+        for word in query.split_whitespace() {
+            if let Some(category) = word.strip_prefix("category:") {
+                filter
+                    .categories
+                    .push(category.replace('_', " ").to_lowercase());
+            } else if let Some(interval) = word.strip_prefix("interval:") {
+                filter.interval = Some(interval.to_string());
+            } else {
+                filter.keywords.push(word.to_lowercase());
+            }
+        }
+
+        filter
+    }
+
+    fn matches(&self, preprint: &Preprint) -> bool {
+        let category = preprint.category.to_lowercase();
+        if !self.categories.is_empty() && !self.categories.contains(&category) {
+            return false;
+        }
+
+        let text = format!("{} {}", preprint.title, preprint.abstract_text).to_lowercase();
+        self.keywords.iter().all(|keyword| text.contains(keyword))
+    }
+}
+
+impl Preprint {
+    fn into_article(self, source: &str) -> Article {
+        let mut article = Article {
+            title: self.title.trim().replace('\n', " "),
+            authors: self
+                .authors
+                .split(';')
+                .map(str::trim)
+                .filter(|a| !a.is_empty())
+                .map(String::from)
+                .collect(),
+            url: format!("https://doi.org/{}", self.doi),
+            published_date: self.date,
+            summary: self.abstract_text.trim().replace('\n', " "),
+            source: source.to_string(),
+            ..Default::default()
+        };
+
+        article
+            .metadata
+            .insert("category".to_string(), self.category);
+        article.metadata.insert("doi".to_string(), self.doi);
+        if let Some(version) = self.version {
+            article.metadata.insert("version".to_string(), version);
+        }
+
+        article
+    }
+}
+
+/// Splits an interval into single-day intervals, newest first; forms other
+/// than `<N>d` and `YYYY-MM-DD/YYYY-MM-DD` are scanned as given
+fn daily_intervals(interval: &str) -> Vec<String> {
+    let today = Utc::now().date_naive();
+    let range = match interval.split_once('/') {
+        Some((start, end)) => NaiveDate::parse_from_str(start, "%Y-%m-%d")
+            .ok()
+            .zip(NaiveDate::parse_from_str(end, "%Y-%m-%d").ok()),
+        None => interval
+            .strip_suffix('d')
+            .and_then(|days| days.parse::<u64>().ok())
+            .filter(|&days| days > 0)
+            .and_then(|days| today.checked_sub_days(Days::new(days - 1)))
+            .map(|start| (start, today)),
+    };
+    let Some((start, end)) = range.filter(|(start, end)| start <= end) else {
+        return vec![interval.to_string()];
+    };
+
+    let mut days = Vec::new();
+    let mut day = Some(end);
+    while let Some(current) = day.filter(|day| *day >= start) {
+        let date = current.format("%Y-%m-%d");
+        days.push(format!("{}/{}", date, date));
+        day = current.pred_opt();
+    }
+    days
+}
+
+#[async_trait]
+impl Collector for BioRxivCollector {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn description(&self) -> &str {
+        match self.server {
+            "medrxiv" => "Collects medRxiv health sciences preprints by category and keyword",
+            _ => "Collects bioRxiv life sciences preprints by category and keyword",
+        }
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let filter = PreprintFilter::parse(query);
+        let interval = filter.interval.as_deref().unwrap_or(&self.interval);
+        let mut articles = Vec::new();
+        let mut pages = 0;
+
+        'days: for interval in daily_intervals(interval) {
+            let mut cursor = 0;
+            loop {
+                if pages == MAX_PAGES {
+                    tracing::warn!(
+                        "Stopped scanning {} after {} pages; narrow the interval to see older preprints",
+                        self.name,
+                        MAX_PAGES
+                    );
+                    break 'days;
+                }
+                pages += 1;
+
+                let url = format!(
+                    "{}/details/{}/{}/{}/json",
+                    self.base_url, self.server, interval, cursor
+                );
+
+                tracing::info!("Fetching from {}: {}", self.name, url);
+
+                let response = self.client.get(&url).send().await?.error_for_status()?;
+                let details: DetailsResponse = response.json().await.map_err(|e| {
+                    tracing::error!("Failed to parse {} response: {}", self.name, e);
+                    format!("Failed to parse {} response: {}", self.name, e)
+                })?;
+
+                let total = details
+                    .messages
+                    .first()
+                    .and_then(|m| m.total.as_ref())
+                    .and_then(|t| match t {
+                        serde_json::Value::Number(n) => n.as_u64(),
+                        serde_json::Value::String(s) => s.parse().ok(),
+                        _ => None,
+                    })
+                    .unwrap_or(0) as usize;
+
+                if details.collection.is_empty() {
+                    break;
+                }
+                cursor += details.collection.len();
+
+                articles.extend(
+                    details
+                        .collection
+                        .into_iter()
+                        .filter(|preprint| filter.matches(preprint))
+                        .map(|preprint| preprint.into_article(self.name)),
+                );
+
+                if articles.len() >= max_results {
+                    break 'days;
+                }
+                if cursor >= total {
+                    break;
+                }
+            }
+        }
+
+        articles.truncate(max_results);
+        Ok(articles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn preprint(title: &str, category: &str) -> Preprint {
+        Preprint {
+            doi: "10.1101/2024.01.02.123456".to_string(),
+            title: title.to_string(),
+            authors: "Franklin, R.; Crick, F.;".to_string(),
+            date: "2024-01-02".to_string(),
+            version: Some("2".to_string()),
+            category: category.to_string(),
+            abstract_text: "An abstract\nover two lines.".to_string(),
+        }
+    }
+
+    #[test]
+    fn filter_parses_categories_interval_and_keywords() {
+        let filter = PreprintFilter::parse("category:Cell_Biology interval:30d Neural  CODING");
+
+        assert_eq!(filter.categories, vec!["cell biology"]);
+        assert_eq!(filter.interval.as_deref(), Some("30d"));
+        assert_eq!(filter.keywords, vec!["neural", "coding"]);
+    }
+
+    #[test]
+    fn filter_matches_category_and_every_keyword() {
+        let filter = PreprintFilter::parse("category:neuroscience neural two");
+
+        assert!(filter.matches(&preprint("Neural circuits", "Neuroscience")));
+        assert!(!filter.matches(&preprint("Neural circuits", "cell biology")));
+        assert!(!filter.matches(&preprint("Glia", "neuroscience")));
+        assert!(PreprintFilter::parse("").matches(&preprint("Glia", "zoology")));
+    }
+
+    #[test]
+    fn preprint_becomes_article() {
+        let article = preprint(" Neural\ncircuits ", "neuroscience").into_article("bioRxiv");
+
+        assert_eq!(article.title, "Neural circuits");
+        assert_eq!(article.authors, vec!["Franklin, R.", "Crick, F."]);
+        assert_eq!(article.url, "https://doi.org/10.1101/2024.01.02.123456");
+        assert_eq!(article.summary, "An abstract over two lines.");
+        assert_eq!(article.source, "bioRxiv");
+        assert_eq!(article.metadata["category"], "neuroscience");
+        assert_eq!(article.metadata["version"], "2");
+    }
+
+    #[test]
+    fn intervals_are_split_into_days_newest_first() {
+        assert_eq!(
+            daily_intervals("2024-02-28/2024-03-01"),
+            vec![
+                "2024-03-01/2024-03-01",
+                "2024-02-29/2024-02-29",
+                "2024-02-28/2024-02-28"
+            ]
+        );
+
+        let today = Utc::now().date_naive().format("%Y-%m-%d").to_string();
+        let days = daily_intervals("3d");
+        assert_eq!(days.len(), 3);
+        assert_eq!(days[0], format!("{}/{}", today, today));
+
+        // Anything else is passed to the API untouched
+        assert_eq!(
+            daily_intervals("2024-03-01/2024-02-01"),
+            vec!["2024-03-01/2024-02-01"]
+        );
+        assert_eq!(daily_intervals("0d"), vec!["0d"]);
+        assert_eq!(daily_intervals("last-week"), vec!["last-week"]);
+    }
+}
@@ -0,0 +1,358 @@
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use tokio::sync::Mutex;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://bsky.social";
+/// searchPosts caps `limit` at 100
+const MAX_LIMIT: usize = 100;
+/// Upper bound on pages fetched when posts without links are skipped
+const MAX_PAGES: usize = 3;
+
+/// Collects articles and papers linked from Bluesky posts via AT Protocol search
+///
+/// The query is passed to `app.bsky.feed.searchPosts`, so Bluesky's search
+/// syntax (`from:<handle>`, `domain:arxiv.org`, `"exact phrase"`) works;
+/// `sort:top` ranks by engagement instead of recency. Each post with an
+/// external embed or a link facet becomes an article for the linked page.
+///
+/// Search requires a session, created from a handle and an app password.
+pub struct BlueskyCollector {
+    client: reqwest::Client,
+    base_url: String,
+    identifier: String,
+    app_password: String,
+    access_jwt: Mutex<Option<String>>,
+}
+
+impl BlueskyCollector {
+    /// Creates a collector logging in as `identifier` (handle or DID) with an
+    /// app password from Settings → Privacy and security → App passwords
+    pub fn new(identifier: impl Into<String>, app_password: impl Into<String>) -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            identifier: identifier.into(),
+            app_password: app_password.into(),
+            access_jwt: Mutex::new(None),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Returns the session's access token, logging in if there is none yet
+    async fn access_jwt(&self) -> CollectorResult<String> {
+        let mut access_jwt = self.access_jwt.lock().await;
+        if let Some(jwt) = access_jwt.as_ref() {
+            return Ok(jwt.clone());
+        }
+
+        let url = format!("{}/xrpc/com.atproto.server.createSession", self.base_url);
+
+        tracing::info!("Creating Bluesky session for {}", self.identifier);
+
+        let response = self
+            .client
+            .post(&url)
+            .json(&CreateSession {
+                identifier: &self.identifier,
+                password: &self.app_password,
+            })
+            .send()
+            .await?;
+        if !response.status().is_success() {
+            return Err(xrpc_error("Bluesky login failed", response).await.into());
+        }
+        let session: Session = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse Bluesky session: {}", e);
+            format!("Failed to parse Bluesky session: {}", e)
+        })?;
+
+        *access_jwt = Some(session.access_jwt.clone());
+        Ok(session.access_jwt)
+    }
+
+    async fn search(&self, params: &[(&str, &str)]) -> CollectorResult<SearchResponse> {
+        let url = format!("{}/xrpc/app.bsky.feed.searchPosts", self.base_url);
+
+        tracing::info!("Fetching from Bluesky: {} ({:?})", url, params);
+
+        // Access tokens are short-lived, so an expired one is replaced once
+        let mut retried = false;
+        let response = loop {
+            let jwt = self.access_jwt().await?;
+            let response = self
+                .client
+                .get(&url)
+                .bearer_auth(jwt)
+                .query(params)
+                .send()
+                .await?;
+            let expired = matches!(
+                response.status(),
+                StatusCode::UNAUTHORIZED | StatusCode::BAD_REQUEST
+            );
+            if !expired || retried {
+                break response;
+            }
+            let message = xrpc_error("Bluesky search failed", response).await;
+            if !message.contains("ExpiredToken") && !message.contains("InvalidToken") {
+                return Err(message.into());
+            }
+            tracing::info!("Bluesky session expired, logging in again");
+            *self.access_jwt.lock().await = None;
+            retried = true;
+        };
+
+        if !response.status().is_success() {
+            return Err(xrpc_error("Bluesky search failed", response).await.into());
+        }
+        response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse Bluesky response: {}", e);
+            format!("Failed to parse Bluesky response: {}", e).into()
+        })
+    }
+}
+
+/// Formats an XRPC error body (`{"error": ..., "message": ...}`)
+async fn xrpc_error(context: &str, response: reqwest::Response) -> String {
+    let status = response.status();
+    match response.json::<XrpcError>().await {
+        Ok(error) => format!(
+            "{}: {}: {}",
+            context,
+            error.error,
+            error.message.unwrap_or_default()
+        ),
+        Err(_) => format!("{}: {}", context, status),
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct CreateSession<'a> {
+    identifier: &'a str,
+    password: &'a str,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Session {
+    access_jwt: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct XrpcError {
+    error: String,
+    message: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    #[serde(default)]
+    posts: Vec<PostView>,
+    cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PostView {
+    /// `at://did:plc:.../app.bsky.feed.post/<rkey>`
+    uri: String,
+    author: Author,
+    record: PostRecord,
+    embed: Option<EmbedView>,
+    like_count: Option<u64>,
+    repost_count: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Author {
+    handle: String,
+    display_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PostRecord {
+    #[serde(default)]
+    text: String,
+    created_at: String,
+    #[serde(default)]
+    facets: Vec<Facet>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Facet {
+    #[serde(default)]
+    features: Vec<FacetFeature>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "$type")]
+enum FacetFeature {
+    #[serde(rename = "app.bsky.richtext.facet#link")]
+    Link { uri: String },
+    /// Mentions and hashtags
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "$type")]
+enum EmbedView {
+    #[serde(rename = "app.bsky.embed.external#view")]
+    External { external: External },
+    #[serde(rename = "app.bsky.embed.recordWithMedia#view")]
+    RecordWithMedia { media: Box<EmbedView> },
+    /// Images, videos and quoted posts
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Deserialize)]
+struct External {
+    uri: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    description: String,
+}
+
+impl EmbedView {
+    fn into_external(self) -> Option<External> {
+        match self {
+            EmbedView::External { external } => Some(external),
+            EmbedView::RecordWithMedia { media } => media.into_external(),
+            EmbedView::Other => None,
+        }
+    }
+}
+
+impl PostView {
+    /// Turns the post into an article for the page it links to, if any
+    fn into_article(self) -> Option<Article> {
+        let external = self.embed.and_then(EmbedView::into_external);
+        let facet_link = self
+            .record
+            .facets
+            .into_iter()
+            .flat_map(|facet| facet.features)
+            .find_map(|feature| match feature {
+                FacetFeature::Link { uri } => Some(uri),
+                FacetFeature::Other => None,
+            });
+
+        let (url, title, description) = match external {
+            Some(external) => (external.uri, external.title, external.description),
+            None => (facet_link?, String::new(), String::new()),
+        };
+        let text = self
+            .record
+            .text
+            .split_whitespace()
+            .collect::<Vec<_>>()
+            .join(" ");
+        let rkey = self.uri.rsplit('/').next().unwrap_or_default();
+
+        let mut article = Article {
+            title: if title.is_empty() { url.clone() } else { title },
+            url,
+            published_date: self.record.created_at,
+            summary: if text.is_empty() { description } else { text },
+            source: "Bluesky".to_string(),
+            ..Default::default()
+        };
+
+        let poster = match self.author.display_name.filter(|name| !name.is_empty()) {
+            Some(name) => format!("{} (@{})", name, self.author.handle),
+            None => format!("@{}", self.author.handle),
+        };
+        article.metadata.insert(
+            "post".to_string(),
+            format!(
+                "https://bsky.app/profile/{}/post/{}",
+                self.author.handle, rkey
+            ),
+        );
+        article.metadata.insert("posted by".to_string(), poster);
+        if let Some(likes) = self.like_count {
+            article
+                .metadata
+                .insert("likes".to_string(), likes.to_string());
+        }
+        if let Some(reposts) = self.repost_count {
+            article
+                .metadata
+                .insert("reposts".to_string(), reposts.to_string());
+        }
+
+        Some(article)
+    }
+}
+
+#[async_trait]
+impl Collector for BlueskyCollector {
+    fn name(&self) -> &str {
+        "Bluesky"
+    }
+
+    fn description(&self) -> &str {
+        "Searches Bluesky posts and collects the articles and papers they link to"
+    }
+
+    fn default_query(&self) -> &str {
+        "domain:arxiv.org"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let mut sort = "latest";
+        let mut words = Vec::new();
+        for word in query.split_whitespace() {
+            match word {
+                "sort:top" => sort = "top",
+                "sort:latest" => sort = "latest",
+                _ => words.push(word),
+            }
+        }
+        let search = words.join(" ");
+        if search.is_empty() {
+            return Err("Bluesky search needs a query".into());
+        }
+
+        let limit = MAX_LIMIT.to_string();
+        let params = [("q", search.as_str()), ("sort", sort), ("limit", &limit)];
+
+        let mut seen_links = HashSet::new();
+        let mut articles = Vec::new();
+        let mut cursor = None;
+        for _ in 0..MAX_PAGES {
+            let mut page_params = params.to_vec();
+            if let Some(cursor) = cursor.as_deref() {
+                page_params.push(("cursor", cursor));
+            }
+            let page = self.search(&page_params).await?;
+
+            articles.extend(
+                page.posts
+                    .into_iter()
+                    .filter_map(PostView::into_article)
+                    .filter(|article| seen_links.insert(article.url.clone())),
+            );
+
+            match page.cursor {
+                Some(next) if articles.len() < max_results => cursor = Some(next),
+                _ => break,
+            }
+        }
+
+        articles.truncate(max_results);
+        Ok(articles)
+    }
+}
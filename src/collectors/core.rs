@@ -0,0 +1,266 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+use serde::Deserialize;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://api.core.ac.uk/v3";
+/// CORE caps `limit` at 100 per request
+const MAX_LIMIT: usize = 100;
+/// Longest rate-limit pause waited out instead of failing the request
+const MAX_WAIT: Duration = Duration::from_secs(10);
+/// Pause assumed when CORE reports no remaining requests but no retry time
+const DEFAULT_RETRY_AFTER: Duration = Duration::from_secs(10);
+
+/// Collects open-access papers with full-text download links from CORE
+///
+/// The query uses CORE search syntax (`title:"graph neural" AND
+/// yearPublished>2022`). CORE's `X-RateLimit-*` headers are tracked, so
+/// requests wait out short pauses and fail fast during long ones.
+pub struct CoreCollector {
+    client: reqwest::Client,
+    base_url: String,
+    api_key: Option<String>,
+    retry_at: Mutex<Option<Instant>>,
+}
+
+impl CoreCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            api_key: None,
+            retry_at: Mutex::new(None),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the API key sent as a bearer token, required beyond light use
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Waits until the rate limit allows another request, or fails if that
+    /// would take longer than `MAX_WAIT`
+    async fn wait_for_rate_limit(&self) -> CollectorResult<()> {
+        let retry_at = *self.retry_at.lock().await;
+        if let Some(retry_at) = retry_at {
+            let wait = retry_at.saturating_duration_since(Instant::now());
+            if wait > MAX_WAIT {
+                return Err(format!(
+                    "CORE rate limit reached, retry in {} seconds",
+                    wait.as_secs()
+                )
+                .into());
+            }
+            tokio::time::sleep(wait).await;
+        }
+        Ok(())
+    }
+
+    /// Records when requests may resume if CORE says the limit is used up
+    async fn track_rate_limit(&self, status: StatusCode, headers: &HeaderMap) {
+        let remaining = headers
+            .get("X-RateLimit-Remaining")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.trim().parse::<u64>().ok());
+
+        let exhausted = status == StatusCode::TOO_MANY_REQUESTS || remaining == Some(0);
+        let retry_at = exhausted.then(|| {
+            let wait = retry_after(headers).unwrap_or(DEFAULT_RETRY_AFTER);
+            tracing::warn!("CORE rate limit reached, pausing for {:?}", wait);
+            Instant::now() + wait
+        });
+
+        *self.retry_at.lock().await = retry_at;
+    }
+
+    async fn search(&self, query: &str, limit: &str) -> CollectorResult<reqwest::Response> {
+        let url = format!("{}/search/works", self.base_url);
+
+        tracing::info!("Fetching from CORE: {} (query: {})", url, query);
+
+        let mut request = self
+            .client
+            .get(&url)
+            .query(&[("q", query), ("limit", limit)]);
+        if let Some(api_key) = &self.api_key {
+            request = request.bearer_auth(api_key);
+        }
+
+        let response = request.send().await?;
+        self.track_rate_limit(response.status(), response.headers())
+            .await;
+        Ok(response)
+    }
+}
+
+impl Default for CoreCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reads `X-RateLimit-Retry-After` or `Retry-After`, as seconds or a date
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers
+        .get("X-RateLimit-Retry-After")
+        .or_else(|| headers.get(RETRY_AFTER))?
+        .to_str()
+        .ok()?
+        .trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = DateTime::parse_from_rfc3339(value)
+        .or_else(|_| DateTime::parse_from_rfc2822(value))
+        .ok()?;
+    (date.with_timezone(&Utc) - Utc::now()).to_std().ok()
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    #[serde(default)]
+    results: Vec<Work>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Work {
+    id: u64,
+    title: Option<String>,
+    #[serde(default)]
+    authors: Vec<Author>,
+    #[serde(rename = "abstract")]
+    abstract_text: Option<String>,
+    doi: Option<String>,
+    download_url: Option<String>,
+    #[serde(default)]
+    links: Vec<Link>,
+    published_date: Option<String>,
+    year_published: Option<u32>,
+    publisher: Option<String>,
+    #[serde(default)]
+    journals: Vec<Journal>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Author {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Link {
+    #[serde(rename = "type")]
+    link_type: String,
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Journal {
+    title: Option<String>,
+}
+
+impl Work {
+    fn into_article(self) -> Article {
+        let link = |link_type: &str| {
+            self.links
+                .iter()
+                .find(|link| link.link_type == link_type)
+                .map(|link| link.url.clone())
+        };
+        let download = self
+            .download_url
+            .clone()
+            .filter(|url| !url.is_empty())
+            .or_else(|| link("download"));
+        let url =
+            link("display").unwrap_or_else(|| format!("https://core.ac.uk/works/{}", self.id));
+
+        let mut article = Article {
+            title: self.title.unwrap_or_default(),
+            authors: self.authors.into_iter().map(|a| a.name).collect(),
+            url,
+            published_date: self
+                .published_date
+                .map(|date| date.split('T').next().unwrap_or_default().to_string())
+                .or_else(|| self.year_published.map(|year| year.to_string()))
+                .unwrap_or_default(),
+            summary: self
+                .abstract_text
+                .unwrap_or_default()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+            source: "CORE".to_string(),
+            ..Default::default()
+        };
+
+        if let Some(download) = download {
+            article.metadata.insert("download".to_string(), download);
+        }
+        if let Some(doi) = self.doi {
+            article.metadata.insert("doi".to_string(), doi);
+        }
+        let journal = self
+            .journals
+            .into_iter()
+            .find_map(|journal| journal.title)
+            .or(self.publisher);
+        if let Some(journal) = journal {
+            article.metadata.insert("journal".to_string(), journal);
+        }
+
+        article
+    }
+}
+
+#[async_trait]
+impl Collector for CoreCollector {
+    fn name(&self) -> &str {
+        "CORE"
+    }
+
+    fn description(&self) -> &str {
+        "Searches CORE for open-access papers with full-text download links"
+    }
+
+    fn default_query(&self) -> &str {
+        "artificial intelligence"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let limit = max_results.clamp(1, MAX_LIMIT).to_string();
+
+        self.wait_for_rate_limit().await?;
+        let mut response = self.search(query, &limit).await?;
+        // A short pause is waited out once rather than failing the command
+        if response.status() == StatusCode::TOO_MANY_REQUESTS {
+            self.wait_for_rate_limit().await?;
+            response = self.search(query, &limit).await?;
+        }
+
+        let search: SearchResponse = response.error_for_status()?.json().await.map_err(|e| {
+            tracing::error!("Failed to parse CORE response: {}", e);
+            format!("Failed to parse CORE response: {}", e)
+        })?;
+
+        Ok(search
+            .results
+            .into_iter()
+            .take(max_results)
+            .map(Work::into_article)
+            .collect())
+    }
+}
@@ -0,0 +1,282 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use url::Url;
+
+use super::html::to_plain_text;
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://api.crossref.org";
+/// Crossref caps `rows` at 1000, but large pages are slow and rarely useful here
+const MAX_ROWS: usize = 100;
+
+/// Collects journal and proceedings metadata from Crossref
+///
+/// A query that is a DOI (`10.1145/...`, `doi:10.1145/...` or a `doi.org`
+/// link) is resolved directly; anything else is a bibliographic search.
+pub struct CrossrefCollector {
+    client: reqwest::Client,
+    base_url: String,
+    mailto: Option<String>,
+}
+
+impl CrossrefCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            mailto: None,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the contact email that puts requests into Crossref's polite pool
+    pub fn with_mailto(mut self, mailto: impl Into<String>) -> Self {
+        self.mailto = Some(mailto.into());
+        self
+    }
+
+    /// Resolves a single DOI into an article
+    pub async fn lookup_doi(&self, doi: &str) -> CollectorResult<Article> {
+        let doi = normalize_doi(doi).unwrap_or(doi);
+        // Old SICI-style DOIs contain `#`, `?` and `<>`, which must not end the path
+        let mut url = Url::parse(&self.base_url)?;
+        url.path_segments_mut()
+            .map_err(|_| format!("Invalid Crossref base URL: {}", self.base_url))?
+            .pop_if_empty()
+            .push("works")
+            .extend(doi.split('/'));
+
+        tracing::info!("Fetching DOI from Crossref: {}", url);
+
+        let mut request = self.client.get(url);
+        if let Some(mailto) = &self.mailto {
+            request = request.query(&[("mailto", mailto)]);
+        }
+
+        let response = request.send().await?.error_for_status()?;
+        let work: WorkResponse = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse Crossref response: {}", e);
+            format!("Failed to parse Crossref response: {}", e)
+        })?;
+
+        Ok(work.message.into_article())
+    }
+
+    async fn search(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let url = format!("{}/works", self.base_url);
+        let rows = max_results.clamp(1, MAX_ROWS).to_string();
+
+        tracing::info!("Fetching from Crossref: {} (query: {})", url, query);
+
+        let mut params = vec![("query.bibliographic", query), ("rows", rows.as_str())];
+        if let Some(mailto) = &self.mailto {
+            params.push(("mailto", mailto.as_str()));
+        }
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&params)
+            .send()
+            .await?
+            .error_for_status()?;
+        let search: SearchResponse = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse Crossref response: {}", e);
+            format!("Failed to parse Crossref response: {}", e)
+        })?;
+
+        Ok(search
+            .message
+            .items
+            .into_iter()
+            .take(max_results)
+            .map(Work::into_article)
+            .collect())
+    }
+}
+
+impl Default for CrossrefCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    message: SearchMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchMessage {
+    #[serde(default)]
+    items: Vec<Work>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkResponse {
+    message: Work,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct Work {
+    #[serde(rename = "DOI")]
+    doi: String,
+    #[serde(default)]
+    title: Vec<String>,
+    #[serde(default)]
+    author: Vec<WorkAuthor>,
+    #[serde(rename = "abstract")]
+    abstract_text: Option<String>,
+    #[serde(default)]
+    container_title: Vec<String>,
+    published: Option<PartialDate>,
+    issued: Option<PartialDate>,
+    #[serde(rename = "type")]
+    work_type: Option<String>,
+    publisher: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct WorkAuthor {
+    given: Option<String>,
+    family: Option<String>,
+    name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+struct PartialDate {
+    #[serde(default)]
+    date_parts: Vec<Vec<Option<i32>>>,
+}
+
+impl PartialDate {
+    /// Formats `[[2024, 3, 1]]` as `2024-03-01`, keeping only the parts present
+    fn format(&self) -> Option<String> {
+        let parts: Vec<i32> = self.date_parts.first()?.iter().map_while(|p| *p).collect();
+        match parts.as_slice() {
+            [year, month, day, ..] => Some(format!("{:04}-{:02}-{:02}", year, month, day)),
+            [year, month] => Some(format!("{:04}-{:02}", year, month)),
+            [year] => Some(format!("{:04}", year)),
+            [] => None,
+        }
+    }
+}
+
+impl WorkAuthor {
+    fn display_name(self) -> Option<String> {
+        match (self.given, self.family, self.name) {
+            (Some(given), Some(family), _) => Some(format!("{} {}", given, family)),
+            (None, Some(family), _) => Some(family),
+            (_, None, name) => name,
+        }
+    }
+}
+
+impl Work {
+    fn into_article(self) -> Article {
+        let published_date = self
+            .published
+            .as_ref()
+            .or(self.issued.as_ref())
+            .and_then(PartialDate::format)
+            .unwrap_or_default();
+
+        let mut article = Article {
+            title: self
+                .title
+                .first()
+                .map(|t| t.trim().replace('\n', " "))
+                .unwrap_or_default(),
+            authors: self
+                .author
+                .into_iter()
+                .filter_map(WorkAuthor::display_name)
+                .collect(),
+            url: format!("https://doi.org/{}", self.doi),
+            published_date,
+            summary: self
+                .abstract_text
+                .as_deref()
+                .map(strip_jats)
+                .unwrap_or_default(),
+            source: "Crossref".to_string(),
+            ..Default::default()
+        };
+
+        article.metadata.insert("doi".to_string(), self.doi);
+        if let Some(venue) = self.container_title.into_iter().next() {
+            article.metadata.insert("venue".to_string(), venue);
+        }
+        if let Some(work_type) = self.work_type {
+            article.metadata.insert("type".to_string(), work_type);
+        }
+        if let Some(publisher) = self.publisher {
+            article.metadata.insert("publisher".to_string(), publisher);
+        }
+
+        article
+    }
+}
+
+/// Extracts the bare DOI from `10.x/...`, `doi:10.x/...` or a `doi.org` URL
+fn normalize_doi(query: &str) -> Option<&str> {
+    let query = query.trim();
+    let doi = [
+        "https://doi.org/",
+        "http://doi.org/",
+        "https://dx.doi.org/",
+        "doi:",
+    ]
+    .iter()
+    .find_map(|prefix| query.strip_prefix(prefix))
+    .unwrap_or(query);
+
+    (doi.starts_with("10.") && doi.contains('/') && !doi.contains(char::is_whitespace))
+        .then_some(doi)
+}
+
+/// Converts a JATS-tagged abstract into plain text
+///
+/// `<jats:title>` headings such as "Abstract" are dropped before the rest
+/// goes through the shared HTML-to-text conversion.
+fn strip_jats(jats: &str) -> String {
+    let mut body = String::with_capacity(jats.len());
+    let mut rest = jats;
+    while let Some(start) = rest.find("<jats:title") {
+        body.push_str(&rest[..start]);
+        rest = rest[start..]
+            .find("</jats:title>")
+            .map_or("", |close| &rest[start + close + "</jats:title>".len()..]);
+    }
+    body.push_str(rest);
+
+    to_plain_text(&body)
+}
+
+#[async_trait]
+impl Collector for CrossrefCollector {
+    fn name(&self) -> &str {
+        "Crossref"
+    }
+
+    fn description(&self) -> &str {
+        "Searches Crossref journal/proceedings metadata or resolves a DOI"
+    }
+
+    fn default_query(&self) -> &str {
+        "artificial intelligence"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        match normalize_doi(query) {
+            Some(doi) => Ok(vec![self.lookup_doi(doi).await?]),
+            None => self.search(query, max_results).await,
+        }
+    }
+}
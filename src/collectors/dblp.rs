@@ -0,0 +1,198 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://dblp.org";
+/// DBLP caps `h` (hits per request) at 1000
+const MAX_HITS: usize = 1000;
+
+/// Collects computer science publications from the DBLP search API
+///
+/// The query is passed to DBLP as-is, so it matches title words, author
+/// names and venues alike (e.g. `yann lecun`, `graph neural networks kdd`).
+pub struct DblpCollector {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl DblpCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+}
+
+impl Default for DblpCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    result: SearchResult,
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResult {
+    hits: Hits,
+}
+
+#[derive(Debug, Deserialize)]
+struct Hits {
+    #[serde(default)]
+    hit: Vec<Hit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Hit {
+    info: PublicationInfo,
+}
+
+#[derive(Debug, Deserialize)]
+struct PublicationInfo {
+    title: String,
+    authors: Option<Authors>,
+    venue: Option<OneOrMany<String>>,
+    year: Option<String>,
+    #[serde(rename = "type")]
+    publication_type: Option<String>,
+    ee: Option<OneOrMany<String>>,
+    url: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Authors {
+    author: OneOrMany<Author>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Author {
+    text: String,
+}
+
+/// DBLP collapses single-element lists into a bare value
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum OneOrMany<T> {
+    One(T),
+    Many(Vec<T>),
+}
+
+impl<T> OneOrMany<T> {
+    fn into_vec(self) -> Vec<T> {
+        match self {
+            Self::One(value) => vec![value],
+            Self::Many(values) => values,
+        }
+    }
+}
+
+impl PublicationInfo {
+    fn into_article(self) -> Article {
+        let authors = self
+            .authors
+            .map(|a| {
+                a.author
+                    .into_vec()
+                    .into_iter()
+                    .map(|author| clean_author_name(&author.text).to_string())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let venue = self.venue.map(|v| v.into_vec().join(", "));
+        let dblp_url = self.url;
+        let url = self
+            .ee
+            .and_then(|ee| ee.into_vec().into_iter().next())
+            .or_else(|| dblp_url.clone())
+            .unwrap_or_default();
+
+        let mut article = Article {
+            title: self.title.trim().trim_end_matches('.').to_string(),
+            authors,
+            url,
+            published_date: self.year.clone().unwrap_or_default(),
+            source: "DBLP".to_string(),
+            ..Default::default()
+        };
+
+        if let Some(venue) = venue {
+            article.metadata.insert("venue".to_string(), venue);
+        }
+        if let Some(year) = self.year {
+            article.metadata.insert("year".to_string(), year);
+        }
+        if let Some(publication_type) = self.publication_type {
+            article
+                .metadata
+                .insert("type".to_string(), publication_type);
+        }
+        if let Some(dblp_url) = dblp_url {
+            article.metadata.insert("dblp".to_string(), dblp_url);
+        }
+
+        article
+    }
+}
+
+/// Strips DBLP's homonym disambiguation suffix ("Wei Wang 0001" -> "Wei Wang")
+fn clean_author_name(name: &str) -> &str {
+    match name.rsplit_once(' ') {
+        Some((base, suffix)) if suffix.len() == 4 && suffix.bytes().all(|b| b.is_ascii_digit()) => {
+            base
+        }
+        _ => name,
+    }
+}
+
+#[async_trait]
+impl Collector for DblpCollector {
+    fn name(&self) -> &str {
+        "DBLP"
+    }
+
+    fn description(&self) -> &str {
+        "Searches DBLP for computer science publications by keyword or author"
+    }
+
+    fn default_query(&self) -> &str {
+        "artificial intelligence"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let url = format!("{}/search/publ/api", self.base_url);
+        let hits = max_results.clamp(1, MAX_HITS).to_string();
+
+        tracing::info!("Fetching from DBLP: {} (query: {})", url, query);
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&[("q", query), ("format", "json"), ("h", hits.as_str())])
+            .send()
+            .await?
+            .error_for_status()?;
+        let search: SearchResponse = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse DBLP response: {}", e);
+            format!("Failed to parse DBLP response: {}", e)
+        })?;
+
+        Ok(search
+            .result
+            .hits
+            .hit
+            .into_iter()
+            .take(max_results)
+            .map(|hit| hit.info.into_article())
+            .collect())
+    }
+}
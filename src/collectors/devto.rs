@@ -0,0 +1,221 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://dev.to";
+const DEFAULT_PER_PAGE: usize = 30;
+
+/// Collects articles from Dev.to through the Forem public API
+///
+/// The articles endpoint has no full-text search, so the query selects
+/// articles instead: bare words are tags, `top:<days>` returns the most
+/// popular articles of the last N days and `username:<name>` limits results
+/// to one author.
+pub struct DevToCollector {
+    client: reqwest::Client,
+    base_url: String,
+    per_page: usize,
+}
+
+impl DevToCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            per_page: DEFAULT_PER_PAGE,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets how many articles are requested per page
+    pub fn with_per_page(mut self, per_page: usize) -> Self {
+        self.per_page = per_page.max(1);
+        self
+    }
+}
+
+impl Default for DevToCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DevToArticle {
+    title: String,
+    description: Option<String>,
+    url: String,
+    published_at: Option<String>,
+    #[serde(default)]
+    tag_list: Vec<String>,
+    reading_time_minutes: Option<u32>,
+    positive_reactions_count: Option<u32>,
+    comments_count: Option<u32>,
+    user: DevToUser,
+}
+
+#[derive(Debug, Deserialize)]
+struct DevToUser {
+    name: String,
+}
+
+impl DevToArticle {
+    fn into_article(self) -> Article {
+        let mut article = Article {
+            title: self.title,
+            authors: vec![self.user.name],
+            url: self.url,
+            published_date: self.published_at.unwrap_or_default(),
+            summary: self.description.unwrap_or_default(),
+            source: "Dev.to".to_string(),
+            ..Default::default()
+        };
+
+        if let Some(minutes) = self.reading_time_minutes {
+            article
+                .metadata
+                .insert("reading time".to_string(), format!("{} min", minutes));
+        }
+        if !self.tag_list.is_empty() {
+            article
+                .metadata
+                .insert("tags".to_string(), self.tag_list.join(", "));
+        }
+        if let Some(reactions) = self.positive_reactions_count {
+            article
+                .metadata
+                .insert("reactions".to_string(), reactions.to_string());
+        }
+        if let Some(comments) = self.comments_count {
+            article
+                .metadata
+                .insert("comments".to_string(), comments.to_string());
+        }
+
+        article
+    }
+}
+
+/// Builds the `/api/articles` filter parameters from the query
+fn query_params(query: &str) -> Vec<(&'static str, String)> {
+    let mut params = Vec::new();
+    let mut tags = Vec::new();
+
+    for word in query.split_whitespace() {
+        if let Some(days) = word.strip_prefix("top:") {
+            params.push(("top", days.to_string()));
+        } else if let Some(username) = word.strip_prefix("username:") {
+            params.push(("username", username.to_string()));
+        } else {
+            tags.push(word.trim_start_matches('#').to_lowercase());
+        }
+    }
+
+    match tags.len() {
+        0 => {}
+        1 => params.push(("tag", tags.remove(0))),
+        _ => params.push(("tags", tags.join(","))),
+    }
+
+    params
+}
+
+#[async_trait]
+impl Collector for DevToCollector {
+    fn name(&self) -> &str {
+        "Dev.to"
+    }
+
+    fn description(&self) -> &str {
+        "Collects developer articles from Dev.to by tag"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let url = format!("{}/api/articles", self.base_url);
+        let params = query_params(query);
+        let per_page = self.per_page.min(max_results.max(1));
+        let mut articles = Vec::new();
+        let mut page = 1;
+
+        while articles.len() < max_results {
+            tracing::info!(
+                "Fetching from Dev.to: {} (query: {}, page {})",
+                url,
+                query,
+                page
+            );
+
+            let response = self
+                .client
+                .get(&url)
+                .query(&params)
+                .query(&[("per_page", per_page), ("page", page)])
+                .send()
+                .await?
+                .error_for_status()?;
+            let batch: Vec<DevToArticle> = response.json().await.map_err(|e| {
+                tracing::error!("Failed to parse Dev.to response: {}", e);
+                format!("Failed to parse Dev.to response: {}", e)
+            })?;
+
+            let last_page = batch.len() < per_page;
+            articles.extend(batch.into_iter().map(DevToArticle::into_article));
+            if last_page {
+                break;
+            }
+            page += 1;
+        }
+
+        articles.truncate(max_results);
+        Ok(articles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_words_become_filters() {
+        assert_eq!(query_params(""), vec![]);
+        assert_eq!(query_params("#Rust"), vec![("tag", "rust".to_string())]);
+        assert_eq!(
+            query_params("rust webdev top:7 username:ferris"),
+            vec![
+                ("top", "7".to_string()),
+                ("username", "ferris".to_string()),
+                ("tags", "rust,webdev".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn api_article_becomes_article() {
+        let api_article: DevToArticle = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "title": "Rust tip",
+            "description": "A short tip.",
+            "url": "https://dev.to/ferris/tip",
+            "published_at": "2024-06-01T10:00:00Z",
+            "tag_list": ["rust", "beginners"],
+            "reading_time_minutes": 4,
+            "positive_reactions_count": 12,
+            "user": {"name": "Ferris", "username": "ferris"}
+        }))
+        .unwrap();
+        let article = api_article.into_article();
+
+        assert_eq!(article.authors, vec!["Ferris"]);
+        assert_eq!(article.summary, "A short tip.");
+        assert_eq!(article.published_date, "2024-06-01T10:00:00Z");
+        assert_eq!(article.metadata["reading time"], "4 min");
+        assert_eq!(article.metadata["tags"], "rust, beginners");
+        assert_eq!(article.metadata["reactions"], "12");
+        assert!(!article.metadata.contains_key("comments"));
+    }
+}
@@ -0,0 +1,354 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::html::to_plain_text;
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://www.ebi.ac.uk/europepmc/webservices/rest";
+/// Europe PMC caps `pageSize` at 1000
+const MAX_PAGE_SIZE: usize = 1000;
+
+/// Collects literature, preprints and open-access full text links from Europe PMC
+///
+/// The query uses Europe PMC search syntax (`malaria AND OPEN_ACCESS:y`,
+/// `SRC:PPR` for preprints). Large result sets are paged with `cursorMark`.
+/// Each article records whether open-access full text is available and,
+/// when it is, a link to it.
+pub struct EuropePmcCollector {
+    client: reqwest::Client,
+    base_url: String,
+    page_size: usize,
+}
+
+impl EuropePmcCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            page_size: MAX_PAGE_SIZE,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets how many results are fetched per request (at most 1000)
+    pub fn with_page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.clamp(1, MAX_PAGE_SIZE);
+        self
+    }
+
+    async fn search_page(
+        &self,
+        query: &str,
+        cursor_mark: &str,
+        page_size: usize,
+    ) -> CollectorResult<SearchResponse> {
+        let url = format!("{}/search", self.base_url);
+        let page_size = page_size.to_string();
+
+        tracing::info!(
+            "Fetching from Europe PMC: {} (query: {}, cursor: {})",
+            url,
+            query,
+            cursor_mark
+        );
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&[
+                ("query", query),
+                ("format", "json"),
+                ("resultType", "core"),
+                ("pageSize", page_size.as_str()),
+                ("cursorMark", cursor_mark),
+            ])
+            .send()
+            .await?
+            .error_for_status()?;
+
+        response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse Europe PMC response: {}", e);
+            format!("Failed to parse Europe PMC response: {}", e).into()
+        })
+    }
+}
+
+impl Default for EuropePmcCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchResponse {
+    next_cursor_mark: Option<String>,
+    result_list: ResultList,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResultList {
+    #[serde(default)]
+    result: Vec<Record>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Record {
+    id: String,
+    source: String,
+    pmcid: Option<String>,
+    doi: Option<String>,
+    title: Option<String>,
+    author_string: Option<String>,
+    journal_info: Option<JournalInfo>,
+    book_or_report_details: Option<BookOrReportDetails>,
+    first_publication_date: Option<String>,
+    pub_year: Option<String>,
+    abstract_text: Option<String>,
+    /// `"Y"` or `"N"`
+    is_open_access: Option<String>,
+    #[serde(rename = "inEPMC")]
+    in_epmc: Option<String>,
+    full_text_url_list: Option<FullTextUrlList>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JournalInfo {
+    journal: Option<Journal>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Journal {
+    title: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BookOrReportDetails {
+    publisher: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FullTextUrlList {
+    #[serde(default)]
+    full_text_url: Vec<FullTextUrl>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct FullTextUrl {
+    /// `OA` (open access), `F` (free), `S` (subscription), ...
+    availability_code: Option<String>,
+    document_style: Option<String>,
+    url: String,
+}
+
+impl Record {
+    /// The open-access or free full text, preferring HTML over PDF, and
+    /// falling back to the Europe PMC copy of a PMC article
+    fn full_text_url(&self) -> Option<String> {
+        let free: Vec<&FullTextUrl> = self
+            .full_text_url_list
+            .iter()
+            .flat_map(|list| &list.full_text_url)
+            .filter(|link| matches!(link.availability_code.as_deref(), Some("OA") | Some("F")))
+            .collect();
+
+        free.iter()
+            .find(|link| link.document_style.as_deref() == Some("html"))
+            .or(free.first())
+            .map(|link| link.url.clone())
+            .or_else(|| {
+                let pmcid = self.pmcid.as_ref()?;
+                (self.in_epmc.as_deref() == Some("Y"))
+                    .then(|| format!("https://europepmc.org/article/PMC/{}", pmcid))
+            })
+    }
+
+    fn into_article(self) -> Article {
+        let full_text = self.full_text_url();
+        let open_access = self.is_open_access.as_deref() == Some("Y");
+
+        let mut article = Article {
+            title: to_plain_text(self.title.as_deref().unwrap_or_default())
+                .trim_end_matches('.')
+                .to_string(),
+            authors: self
+                .author_string
+                .as_deref()
+                .unwrap_or_default()
+                .trim_end_matches('.')
+                .split(", ")
+                .map(str::trim)
+                .filter(|author| !author.is_empty())
+                .map(String::from)
+                .collect(),
+            url: format!("https://europepmc.org/article/{}/{}", self.source, self.id),
+            published_date: self
+                .first_publication_date
+                .or(self.pub_year)
+                .unwrap_or_default(),
+            summary: to_plain_text(self.abstract_text.as_deref().unwrap_or_default()),
+            source: "Europe PMC".to_string(),
+            ..Default::default()
+        };
+
+        let journal = self
+            .journal_info
+            .and_then(|info| info.journal)
+            .and_then(|journal| journal.title)
+            .or_else(|| self.book_or_report_details.and_then(|d| d.publisher));
+        if let Some(journal) = journal {
+            article.metadata.insert("journal".to_string(), journal);
+        }
+        if self.source == "PPR" {
+            article
+                .metadata
+                .insert("type".to_string(), "preprint".to_string());
+        }
+        if let Some(doi) = self.doi {
+            article.metadata.insert("doi".to_string(), doi);
+        }
+        article.metadata.insert(
+            "open access".to_string(),
+            if open_access { "yes" } else { "no" }.to_string(),
+        );
+        if let Some(full_text) = full_text {
+            article.metadata.insert("full text".to_string(), full_text);
+        }
+
+        article
+    }
+}
+
+#[async_trait]
+impl Collector for EuropePmcCollector {
+    fn name(&self) -> &str {
+        "Europe PMC"
+    }
+
+    fn description(&self) -> &str {
+        "Collects papers and preprints from Europe PMC, flagging open-access full text"
+    }
+
+    fn default_query(&self) -> &str {
+        "artificial intelligence"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let page_size = self.page_size.min(max_results.max(1));
+        let mut cursor_mark = "*".to_string();
+        let mut articles = Vec::new();
+
+        while articles.len() < max_results {
+            let page = self.search_page(query, &cursor_mark, page_size).await?;
+            let results = page.result_list.result;
+            let last_page = results.len() < page_size;
+            articles.extend(results.into_iter().map(Record::into_article));
+
+            // The cursor stops advancing once the last page has been served
+            match page.next_cursor_mark {
+                Some(next) if !last_page && next != cursor_mark => cursor_mark = next,
+                _ => break,
+            }
+        }
+
+        articles.truncate(max_results);
+        Ok(articles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(value: serde_json::Value) -> Record {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn preprint_record_becomes_article() {
+        let article = record(serde_json::json!({
+            "id": "PPR123",
+            "source": "PPR",
+            "doi": "10.1101/2024.01.01.123456",
+            "title": "A <i>CRISPR</i> screen.",
+            "authorString": "Smith A, Jones B.",
+            "bookOrReportDetails": {"publisher": "bioRxiv"},
+            "firstPublicationDate": "2024-01-02",
+            "abstractText": "<h4>Background</h4>We screen.",
+            "isOpenAccess": "Y"
+        }))
+        .into_article();
+
+        assert_eq!(article.title, "A CRISPR screen");
+        assert_eq!(article.authors, vec!["Smith A", "Jones B"]);
+        assert_eq!(article.url, "https://europepmc.org/article/PPR/PPR123");
+        assert_eq!(article.published_date, "2024-01-02");
+        assert_eq!(article.summary, "Background We screen.");
+        assert_eq!(article.metadata["journal"], "bioRxiv");
+        assert_eq!(article.metadata["type"], "preprint");
+        assert_eq!(article.metadata["doi"], "10.1101/2024.01.01.123456");
+        assert_eq!(article.metadata["open access"], "yes");
+    }
+
+    #[test]
+    fn full_text_prefers_free_html() {
+        let full_text = |links: serde_json::Value| {
+            record(serde_json::json!({
+                "id": "1",
+                "source": "MED",
+                "fullTextUrlList": {"fullTextUrl": links}
+            }))
+            .full_text_url()
+        };
+
+        assert_eq!(
+            full_text(serde_json::json!([
+                {"availabilityCode": "S", "documentStyle": "html", "url": "https://paywall.example/1"},
+                {"availabilityCode": "OA", "documentStyle": "pdf", "url": "https://example.org/1.pdf"}
+            ])),
+            Some("https://example.org/1.pdf".to_string())
+        );
+        assert_eq!(
+            full_text(serde_json::json!([
+                {"availabilityCode": "F", "documentStyle": "pdf", "url": "https://example.org/1.pdf"},
+                {"availabilityCode": "OA", "documentStyle": "html", "url": "https://example.org/1"}
+            ])),
+            Some("https://example.org/1".to_string())
+        );
+        assert_eq!(
+            full_text(serde_json::json!([
+                {"availabilityCode": "S", "documentStyle": "html", "url": "https://paywall.example/1"}
+            ])),
+            None
+        );
+    }
+
+    #[test]
+    fn full_text_falls_back_to_europe_pmc_copy() {
+        let in_epmc = record(serde_json::json!({
+            "id": "38000001",
+            "source": "MED",
+            "pmcid": "PMC9000001",
+            "inEPMC": "Y"
+        }));
+        assert_eq!(
+            in_epmc.full_text_url(),
+            Some("https://europepmc.org/article/PMC/PMC9000001".to_string())
+        );
+
+        let not_in_epmc = record(serde_json::json!({
+            "id": "38000002",
+            "source": "MED",
+            "pmcid": "PMC9000002",
+            "inEPMC": "N"
+        }));
+        assert_eq!(not_in_epmc.full_text_url(), None);
+    }
+}
@@ -4,6 +4,7 @@ use super::{Article, Collector, CollectorResult};
 
 /// Example collector for general article/news sites
 /// This is a mock implementation that demonstrates how to add more collectors
+/// See `DevToCollector` and `HackerNewsCollector` for real article site collectors
 #[derive(Default)]
 pub struct ExampleArticleCollector {
     // In a real implementation, you might have fields like:
@@ -44,8 +45,6 @@ impl Collector for ExampleArticleCollector {
         // For demonstration, return an empty list
         // You can replace this with actual API calls to sites like:
         // - Medium API
-        // - Dev.to API
-        // - Hacker News API
         // - Research paper databases
 
         Ok(vec![])
@@ -0,0 +1,300 @@
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+use quick_xml::de::from_str;
+use serde::Deserialize;
+
+use super::html::to_plain_text;
+use super::{http_client, Article, Collector, CollectorResult};
+
+/// Collects articles from one or more RSS 2.0, Atom 1.0 or JSON Feed 1.1 feeds
+///
+/// Each instance is named, so several feed collectors (lab blogs, company
+/// research blogs, newsletters) can be registered side by side. The query is
+/// a keyword filter: every word must appear in an item's title or summary.
+pub struct FeedCollector {
+    client: reqwest::Client,
+    name: String,
+    description: String,
+    urls: Vec<String>,
+}
+
+impl FeedCollector {
+    pub fn new(name: impl Into<String>, urls: Vec<String>) -> Self {
+        let name = name.into();
+        Self {
+            client: http_client(),
+            description: format!("Collects articles from the {} feed", name),
+            name,
+            urls,
+        }
+    }
+
+    /// Overrides the description shown by `/sources`
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    async fn fetch(&self, url: &str) -> CollectorResult<Vec<Article>> {
+        tracing::info!("Fetching feed {}: {}", self.name, url);
+
+        let response = self.client.get(url).send().await?.error_for_status()?;
+        let body = response.text().await?;
+
+        parse_feed(&body, &self.name).map_err(|e| {
+            tracing::error!("Failed to parse feed {}: {}", url, e);
+            format!("Failed to parse feed {}: {}", url, e).into()
+        })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Rss {
+    channel: RssChannel,
+}
+
+#[derive(Debug, Deserialize)]
+struct RssChannel {
+    #[serde(rename = "item", default)]
+    items: Vec<RssItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RssItem {
+    title: Option<String>,
+    link: Option<String>,
+    guid: Option<String>,
+    description: Option<String>,
+    #[serde(rename = "pubDate")]
+    pub_date: Option<String>,
+    #[serde(rename = "author", default)]
+    authors: Vec<String>,
+    /// `dc:creator`, matched by local name; multi-author posts repeat it
+    #[serde(rename = "creator", default)]
+    creators: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AtomFeed {
+    #[serde(rename = "entry", default)]
+    entries: Vec<AtomEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AtomEntry {
+    title: Option<String>,
+    id: Option<String>,
+    #[serde(rename = "link", default)]
+    links: Vec<AtomLink>,
+    published: Option<String>,
+    updated: Option<String>,
+    summary: Option<AtomText>,
+    content: Option<AtomText>,
+    #[serde(rename = "author", default)]
+    authors: Vec<AtomAuthor>,
+}
+
+/// An Atom text construct; the markup nested in `type="xhtml"` is skipped
+#[derive(Debug, Deserialize)]
+struct AtomText {
+    #[serde(rename = "$text")]
+    text: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AtomLink {
+    #[serde(rename = "@href")]
+    href: String,
+    #[serde(rename = "@rel")]
+    rel: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AtomAuthor {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonFeed {
+    #[serde(default)]
+    items: Vec<JsonFeedItem>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonFeedItem {
+    id: Option<serde_json::Value>,
+    url: Option<String>,
+    external_url: Option<String>,
+    title: Option<String>,
+    summary: Option<String>,
+    content_text: Option<String>,
+    content_html: Option<String>,
+    date_published: Option<String>,
+    #[serde(default)]
+    authors: Vec<JsonFeedAuthor>,
+    /// JSON Feed 1.0 single author, still common in the wild
+    author: Option<JsonFeedAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+struct JsonFeedAuthor {
+    name: Option<String>,
+}
+
+impl RssItem {
+    fn into_article(self, source: &str) -> Article {
+        let authors = if self.creators.is_empty() {
+            self.authors
+        } else {
+            self.creators
+        };
+        Article {
+            title: to_plain_text(&self.title.unwrap_or_default()),
+            authors,
+            url: self.link.or(self.guid).unwrap_or_default(),
+            published_date: self.pub_date.unwrap_or_default(),
+            summary: to_plain_text(&self.description.unwrap_or_default()),
+            source: source.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+impl AtomEntry {
+    fn into_article(self, source: &str) -> Article {
+        let url = self
+            .links
+            .iter()
+            .find(|l| l.rel.as_deref().unwrap_or("alternate") == "alternate")
+            .or(self.links.first())
+            .map(|l| l.href.clone())
+            .or(self.id)
+            .unwrap_or_default();
+
+        Article {
+            title: to_plain_text(&self.title.unwrap_or_default()),
+            authors: self.authors.into_iter().map(|a| a.name).collect(),
+            url,
+            published_date: self.published.or(self.updated).unwrap_or_default(),
+            summary: to_plain_text(
+                &[self.summary, self.content]
+                    .into_iter()
+                    .flatten()
+                    .find_map(|text| text.text.filter(|text| !text.trim().is_empty()))
+                    .unwrap_or_default(),
+            ),
+            source: source.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+impl JsonFeedItem {
+    fn into_article(self, source: &str) -> Article {
+        let summary = self
+            .summary
+            .or(self.content_text)
+            .or_else(|| self.content_html.map(|html| to_plain_text(&html)))
+            .unwrap_or_default();
+        let id = self.id.and_then(|id| id.as_str().map(String::from));
+
+        Article {
+            title: self.title.unwrap_or_default(),
+            authors: self
+                .authors
+                .into_iter()
+                .chain(self.author)
+                .filter_map(|a| a.name)
+                .collect(),
+            url: self.url.or(self.external_url).or(id).unwrap_or_default(),
+            published_date: self.date_published.unwrap_or_default(),
+            summary,
+            source: source.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Detects the feed format and parses every item into an article
+fn parse_feed(body: &str, source: &str) -> CollectorResult<Vec<Article>> {
+    let trimmed = body.trim_start_matches('\u{feff}').trim_start();
+
+    if trimmed.starts_with('{') {
+        let feed: JsonFeed = serde_json::from_str(trimmed)?;
+        return Ok(feed
+            .items
+            .into_iter()
+            .map(|item| item.into_article(source))
+            .collect());
+    }
+
+    if trimmed.contains("<rss") {
+        let rss: Rss = from_str(trimmed)?;
+        Ok(rss
+            .channel
+            .items
+            .into_iter()
+            .map(|item| item.into_article(source))
+            .collect())
+    } else if trimmed.contains("<feed") {
+        let feed: AtomFeed = from_str(trimmed)?;
+        Ok(feed
+            .entries
+            .into_iter()
+            .map(|entry| entry.into_article(source))
+            .collect())
+    } else {
+        Err("Unrecognized feed format".into())
+    }
+}
+
+/// Parses RFC 3339 (Atom, JSON Feed) and RFC 2822 (RSS) dates
+fn parse_date(date: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(date)
+        .or_else(|_| DateTime::parse_from_rfc2822(date))
+        .ok()
+}
+
+#[async_trait]
+impl Collector for FeedCollector {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let keywords: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        let mut articles = Vec::new();
+        let mut last_error = None;
+
+        for url in &self.urls {
+            match self.fetch(url).await {
+                Ok(items) => articles.extend(items),
+                Err(e) => {
+                    tracing::error!("Error collecting feed {}: {}", url, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        // Surface the error only when no feed produced anything
+        if articles.is_empty() {
+            if let Some(e) = last_error {
+                return Err(e);
+            }
+        }
+
+        articles.retain(|article| {
+            let text = format!("{} {}", article.title, article.summary).to_lowercase();
+            keywords.iter().all(|keyword| text.contains(keyword))
+        });
+        // Newest first across all feeds; undated items go last
+        articles.sort_by_key(|article| std::cmp::Reverse(parse_date(&article.published_date)));
+        articles.truncate(max_results);
+
+        Ok(articles)
+    }
+}
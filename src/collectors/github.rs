@@ -0,0 +1,319 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://api.github.com";
+/// GitHub caps `per_page` at 100
+const MAX_PER_PAGE: usize = 100;
+
+/// Collects repositories and releases from the GitHub REST API
+///
+/// By default the query is a GitHub repository search (`topic:rust
+/// stars:>1000 pushed:>2024-01-01`). The word `releases` instead lists the
+/// latest releases of the watched repositories, and `releases:owner/name`
+/// lists the releases of a specific repository.
+pub struct GitHubCollector {
+    client: reqwest::Client,
+    base_url: String,
+    token: Option<String>,
+    watched_repos: Vec<String>,
+}
+
+impl GitHubCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            token: None,
+            watched_repos: Vec::new(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the token sent as a bearer token for higher rate limits
+    pub fn with_token(mut self, token: impl Into<String>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Sets the `owner/name` repositories whose releases are watched
+    pub fn with_watched_repos(mut self, repos: Vec<String>) -> Self {
+        self.watched_repos = repos;
+        self
+    }
+
+    fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        let mut request = self
+            .client
+            .get(url)
+            .header("Accept", "application/vnd.github+json")
+            .header("User-Agent", "xplorer")
+            .header("X-GitHub-Api-Version", "2022-11-28");
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+        request
+    }
+
+    async fn search_repositories(
+        &self,
+        query: &str,
+        max_results: usize,
+    ) -> CollectorResult<Vec<Article>> {
+        let url = format!("{}/search/repositories", self.base_url);
+        let per_page = max_results.clamp(1, MAX_PER_PAGE).to_string();
+
+        tracing::info!("Fetching from GitHub: {} (query: {})", url, query);
+
+        let response = self
+            .get(&url)
+            .query(&[
+                ("q", query),
+                ("sort", "stars"),
+                ("order", "desc"),
+                ("per_page", per_page.as_str()),
+            ])
+            .send()
+            .await?
+            .error_for_status()?;
+        let search: SearchResponse = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse GitHub response: {}", e);
+            format!("Failed to parse GitHub response: {}", e)
+        })?;
+
+        Ok(search
+            .items
+            .into_iter()
+            .take(max_results)
+            .map(Repository::into_article)
+            .collect())
+    }
+
+    async fn repo_releases(&self, repo: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let url = format!("{}/repos/{}/releases", self.base_url, repo);
+        let per_page = max_results.clamp(1, MAX_PER_PAGE).to_string();
+
+        tracing::info!("Fetching releases from GitHub: {}", url);
+
+        let response = self
+            .get(&url)
+            .query(&[("per_page", per_page.as_str())])
+            .send()
+            .await?
+            .error_for_status()?;
+        let releases: Vec<Release> = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse GitHub releases for {}: {}", repo, e);
+            format!("Failed to parse GitHub releases for {}: {}", repo, e)
+        })?;
+
+        Ok(releases
+            .into_iter()
+            .filter(|release| !release.draft)
+            .map(|release| release.into_article(repo))
+            .collect())
+    }
+
+    async fn releases(
+        &self,
+        repos: &[String],
+        max_results: usize,
+    ) -> CollectorResult<Vec<Article>> {
+        // Repository names go into the URL path, so nothing else may get through
+        if let Some(repo) = repos.iter().find(|repo| !is_repo_name(repo)) {
+            return Err(
+                format!("Invalid GitHub repository {:?}, expected owner/name", repo).into(),
+            );
+        }
+
+        let mut articles = Vec::new();
+        let mut last_error = None;
+
+        for repo in repos {
+            match self.repo_releases(repo, max_results).await {
+                Ok(releases) => articles.extend(releases),
+                Err(e) => {
+                    tracing::error!("Error collecting releases of {}: {}", repo, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        if articles.is_empty() {
+            if let Some(e) = last_error {
+                return Err(e);
+            }
+        }
+
+        // RFC 3339 timestamps sort chronologically as strings
+        articles.sort_by(|a, b| b.published_date.cmp(&a.published_date));
+        articles.truncate(max_results);
+        Ok(articles)
+    }
+}
+
+impl Default for GitHubCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    #[serde(default)]
+    items: Vec<Repository>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Repository {
+    full_name: String,
+    html_url: String,
+    description: Option<String>,
+    owner: Owner,
+    stargazers_count: Option<u64>,
+    language: Option<String>,
+    #[serde(default)]
+    topics: Vec<String>,
+    pushed_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Owner {
+    login: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    name: Option<String>,
+    html_url: String,
+    body: Option<String>,
+    author: Option<Owner>,
+    published_at: Option<String>,
+    #[serde(default)]
+    draft: bool,
+    #[serde(default)]
+    prerelease: bool,
+}
+
+impl Repository {
+    fn into_article(self) -> Article {
+        let mut article = Article {
+            title: self.full_name,
+            authors: vec![self.owner.login],
+            url: self.html_url,
+            published_date: self.pushed_at.unwrap_or_default(),
+            summary: self.description.unwrap_or_default(),
+            source: "GitHub".to_string(),
+            ..Default::default()
+        };
+
+        if let Some(stars) = self.stargazers_count {
+            article
+                .metadata
+                .insert("stars".to_string(), stars.to_string());
+        }
+        if let Some(language) = self.language {
+            article.metadata.insert("language".to_string(), language);
+        }
+        if !self.topics.is_empty() {
+            article
+                .metadata
+                .insert("topics".to_string(), self.topics.join(", "));
+        }
+
+        article
+    }
+}
+
+impl Release {
+    fn into_article(self, repo: &str) -> Article {
+        let name = self
+            .name
+            .filter(|n| !n.trim().is_empty())
+            .unwrap_or_else(|| self.tag_name.clone());
+
+        let mut article = Article {
+            title: format!("{} {}", repo, name),
+            authors: self.author.into_iter().map(|a| a.login).collect(),
+            url: self.html_url,
+            published_date: self.published_at.unwrap_or_default(),
+            summary: self
+                .body
+                .unwrap_or_default()
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+            source: "GitHub".to_string(),
+            ..Default::default()
+        };
+
+        article
+            .metadata
+            .insert("repository".to_string(), repo.to_string());
+        article.metadata.insert("tag".to_string(), self.tag_name);
+        if self.prerelease {
+            article
+                .metadata
+                .insert("prerelease".to_string(), "yes".to_string());
+        }
+
+        article
+    }
+}
+
+/// Whether `repo` is `owner/name` with only the characters GitHub allows
+fn is_repo_name(repo: &str) -> bool {
+    let valid = |part: &str| {
+        !part.is_empty()
+            && part != "."
+            && part != ".."
+            && part
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+    };
+    repo.split_once('/')
+        .is_some_and(|(owner, name)| valid(owner) && valid(name))
+}
+
+#[async_trait]
+impl Collector for GitHubCollector {
+    fn name(&self) -> &str {
+        "GitHub"
+    }
+
+    fn description(&self) -> &str {
+        "Searches GitHub repositories or lists releases of watched repositories"
+    }
+
+    fn default_query(&self) -> &str {
+        if self.watched_repos.is_empty() {
+            "topic:machine-learning"
+        } else {
+            "releases"
+        }
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let words: Vec<&str> = query.split_whitespace().collect();
+
+        if words == ["releases"] {
+            return self.releases(&self.watched_repos, max_results).await;
+        }
+
+        let repos: Vec<String> = words
+            .iter()
+            .filter_map(|word| word.strip_prefix("releases:"))
+            .map(String::from)
+            .collect();
+        if !repos.is_empty() {
+            return self.releases(&repos, max_results).await;
+        }
+
+        self.search_repositories(query, max_results).await
+    }
+}
@@ -0,0 +1,193 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://hn.algolia.com/api/v1";
+/// Algolia caps `hitsPerPage` at 1000, but one reply lists at most 20 stories
+const MAX_HITS_PER_PAGE: usize = 100;
+
+/// Collects Hacker News stories through the HN Algolia search API
+///
+/// Besides search words, the query accepts thresholds such as `points>100`
+/// or `comments>=20`, and `sort:date` to list the newest stories first
+/// instead of the most relevant ones.
+pub struct HackerNewsCollector {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl HackerNewsCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+}
+
+impl Default for HackerNewsCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    #[serde(default)]
+    hits: Vec<Hit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Hit {
+    #[serde(rename = "objectID")]
+    object_id: String,
+    title: Option<String>,
+    url: Option<String>,
+    author: Option<String>,
+    points: Option<i64>,
+    num_comments: Option<i64>,
+    created_at: Option<String>,
+}
+
+impl Hit {
+    fn into_article(self) -> Article {
+        let discussion_url = format!("https://news.ycombinator.com/item?id={}", self.object_id);
+
+        let mut article = Article {
+            title: self.title.unwrap_or_default(),
+            authors: self.author.into_iter().collect(),
+            // Ask HN and other text posts have no external link
+            url: self
+                .url
+                .filter(|u| !u.is_empty())
+                .unwrap_or_else(|| discussion_url.clone()),
+            published_date: self.created_at.unwrap_or_default(),
+            source: "Hacker News".to_string(),
+            ..Default::default()
+        };
+
+        if let Some(points) = self.points {
+            article
+                .metadata
+                .insert("points".to_string(), points.to_string());
+        }
+        if let Some(comments) = self.num_comments {
+            article
+                .metadata
+                .insert("comments".to_string(), comments.to_string());
+        }
+        article
+            .metadata
+            .insert("discussion".to_string(), discussion_url);
+
+        article
+    }
+}
+
+/// Search words, Algolia `numericFilters` and endpoint parsed from the query
+struct HnQuery {
+    words: Vec<String>,
+    numeric_filters: Vec<String>,
+    by_date: bool,
+}
+
+impl HnQuery {
+    fn parse(query: &str) -> Self {
+        let mut parsed = Self {
+            words: Vec::new(),
+            numeric_filters: Vec::new(),
+            by_date: false,
+        };
+
+        for word in query.split_whitespace() {
+            if word == "sort:date" {
+                parsed.by_date = true;
+            } else if let Some(filter) = numeric_filter(word) {
+                parsed.numeric_filters.push(filter);
+            } else {
+                parsed.words.push(word.to_string());
+            }
+        }
+
+        parsed
+    }
+}
+
+/// Turns `points>100` or `comments>=20` into an Algolia numeric filter
+fn numeric_filter(word: &str) -> Option<String> {
+    let (field, rest) = if let Some(rest) = word.strip_prefix("points") {
+        ("points", rest)
+    } else if let Some(rest) = word.strip_prefix("comments") {
+        ("num_comments", rest)
+    } else {
+        return None;
+    };
+
+    let operator = [">=", "<=", ">", "<", "="]
+        .into_iter()
+        .find(|op| rest.starts_with(op))?;
+    let value = &rest[operator.len()..];
+    value.parse::<u64>().ok()?;
+
+    Some(format!("{}{}{}", field, operator, value))
+}
+
+#[async_trait]
+impl Collector for HackerNewsCollector {
+    fn name(&self) -> &str {
+        "Hacker News"
+    }
+
+    fn description(&self) -> &str {
+        "Collects Hacker News stories, with optional points/comments thresholds"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let parsed = HnQuery::parse(query);
+        let endpoint = if parsed.by_date {
+            "search_by_date"
+        } else {
+            "search"
+        };
+        let url = format!("{}/{}", self.base_url, endpoint);
+        let search = parsed.words.join(" ");
+        let hits_per_page = max_results.clamp(1, MAX_HITS_PER_PAGE).to_string();
+        let numeric_filters = parsed.numeric_filters.join(",");
+
+        tracing::info!("Fetching from Hacker News: {} (query: {})", url, query);
+
+        let mut params = vec![
+            ("query", search.as_str()),
+            ("tags", "story"),
+            ("hitsPerPage", hits_per_page.as_str()),
+        ];
+        if !numeric_filters.is_empty() {
+            params.push(("numericFilters", numeric_filters.as_str()));
+        }
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&params)
+            .send()
+            .await?
+            .error_for_status()?;
+        let results: SearchResponse = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse Hacker News response: {}", e);
+            format!("Failed to parse Hacker News response: {}", e)
+        })?;
+
+        Ok(results
+            .hits
+            .into_iter()
+            .take(max_results)
+            .map(Hit::into_article)
+            .collect())
+    }
+}
@@ -0,0 +1,175 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://huggingface.co";
+/// The daily papers API caps `limit` at 100
+const MAX_LIMIT: usize = 100;
+
+/// Collects the curated Hugging Face daily papers, most upvoted first
+///
+/// Without a date the papers of the newest day in the feed are returned;
+/// `date:YYYY-MM-DD` picks another day. Any other query words filter the papers by title
+/// and abstract. Each article links the Hugging Face discussion page and
+/// carries the arXiv abstract link in its metadata.
+pub struct HuggingFacePapersCollector {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl HuggingFacePapersCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+}
+
+impl Default for HuggingFacePapersCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DailyPaper {
+    paper: Paper,
+    published_at: Option<String>,
+    num_comments: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Paper {
+    /// The arXiv id
+    id: String,
+    title: String,
+    #[serde(default)]
+    summary: String,
+    #[serde(default)]
+    authors: Vec<Author>,
+    published_at: Option<String>,
+    #[serde(default)]
+    upvotes: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct Author {
+    name: String,
+}
+
+impl DailyPaper {
+    /// The `YYYY-MM-DD` part of the date the paper was featured
+    fn day(&self) -> Option<&str> {
+        let published_at = self
+            .published_at
+            .as_deref()
+            .or(self.paper.published_at.as_deref())?;
+        published_at.get(..10)
+    }
+
+    fn into_article(self) -> Article {
+        let paper = self.paper;
+
+        let mut article = Article {
+            title: paper.title.split_whitespace().collect::<Vec<_>>().join(" "),
+            authors: paper.authors.into_iter().map(|a| a.name).collect(),
+            url: format!("https://huggingface.co/papers/{}", paper.id),
+            published_date: self.published_at.or(paper.published_at).unwrap_or_default(),
+            summary: paper
+                .summary
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+            source: "Hugging Face Papers".to_string(),
+            ..Default::default()
+        };
+
+        article
+            .metadata
+            .insert("upvotes".to_string(), paper.upvotes.to_string());
+        if let Some(comments) = self.num_comments {
+            article
+                .metadata
+                .insert("comments".to_string(), comments.to_string());
+        }
+        article.metadata.insert(
+            "arxiv".to_string(),
+            format!("https://arxiv.org/abs/{}", paper.id),
+        );
+
+        article
+    }
+}
+
+#[async_trait]
+impl Collector for HuggingFacePapersCollector {
+    fn name(&self) -> &str {
+        "Hugging Face Papers"
+    }
+
+    fn description(&self) -> &str {
+        "Collects the Hugging Face daily papers with upvotes and arXiv links"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let mut date = None;
+        let mut keywords = Vec::new();
+        for word in query.split_whitespace() {
+            match word.strip_prefix("date:") {
+                Some(day) => date = Some(day),
+                None => keywords.push(word.to_lowercase()),
+            }
+        }
+
+        let url = format!("{}/api/daily_papers", self.base_url);
+        let limit = MAX_LIMIT.to_string();
+        let mut params = vec![("limit", limit.as_str())];
+        if let Some(date) = date {
+            params.push(("date", date));
+        }
+
+        tracing::info!("Fetching from Hugging Face: {} ({:?})", url, params);
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&params)
+            .send()
+            .await?
+            .error_for_status()?;
+        let mut papers: Vec<DailyPaper> = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse Hugging Face response: {}", e);
+            format!("Failed to parse Hugging Face response: {}", e)
+        })?;
+
+        // Without a date the API returns the last few days mixed together
+        if date.is_none() {
+            let newest = papers
+                .iter()
+                .filter_map(DailyPaper::day)
+                .max()
+                .map(String::from);
+            papers.retain(|daily| daily.day() == newest.as_deref());
+        }
+        papers.retain(|daily| {
+            let text = format!("{} {}", daily.paper.title, daily.paper.summary).to_lowercase();
+            keywords.iter().all(|keyword| text.contains(keyword))
+        });
+        papers.sort_by_key(|daily| std::cmp::Reverse(daily.paper.upvotes));
+
+        Ok(papers
+            .into_iter()
+            .take(max_results)
+            .map(DailyPaper::into_article)
+            .collect())
+    }
+}
@@ -0,0 +1,186 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://datatracker.ietf.org";
+/// Datatracker caps `limit` at 1000, but one reply only needs a page
+const MAX_LIMIT: usize = 100;
+
+/// Collects recently updated RFCs and Internet-Drafts from the IETF Datatracker
+///
+/// `wg:<acronym>` (e.g. `wg:quic`) limits results to a working group and
+/// `type:rfc` or `type:draft` to one kind of document; any other words are
+/// matched as one phrase in the title (Datatracker takes a single
+/// `title__icontains`). Each draft revision gets its own link, so a new
+/// `-01` shows up as a new article after `-00`.
+pub struct IetfCollector {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl IetfCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+}
+
+impl Default for IetfCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct DocumentList {
+    #[serde(default)]
+    objects: Vec<Document>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Document {
+    /// `draft-ietf-quic-transport` or `rfc9000`
+    name: String,
+    rev: Option<String>,
+    title: String,
+    #[serde(rename = "abstract", default)]
+    abstract_text: String,
+    time: Option<String>,
+    /// Resource URI such as `/api/v1/name/doctypename/draft/`
+    #[serde(rename = "type")]
+    doc_type: Option<String>,
+    pages: Option<u32>,
+    /// Resource URI such as `/api/v1/name/stdlevelname/ps/`
+    std_level: Option<String>,
+}
+
+/// The last path segment of a Datatracker resource URI
+fn resource_slug(uri: &str) -> &str {
+    uri.trim_end_matches('/').rsplit('/').next().unwrap_or(uri)
+}
+
+impl Document {
+    fn into_article(self, group: Option<&str>) -> Article {
+        let is_rfc = self.doc_type.as_deref().map(resource_slug) == Some("rfc");
+        let revision = self.rev.filter(|rev| !rev.is_empty() && !is_rfc);
+        let url = match &revision {
+            Some(rev) => format!("https://datatracker.ietf.org/doc/{}/{}/", self.name, rev),
+            None => format!("https://datatracker.ietf.org/doc/{}/", self.name),
+        };
+
+        let mut article = Article {
+            title: self.title,
+            url,
+            published_date: self.time.unwrap_or_default(),
+            summary: self
+                .abstract_text
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+            source: "IETF".to_string(),
+            ..Default::default()
+        };
+
+        if is_rfc {
+            article.metadata.insert(
+                "rfc".to_string(),
+                self.name.trim_start_matches("rfc").to_string(),
+            );
+        } else {
+            let draft = match &revision {
+                Some(rev) => format!("{}-{}", self.name, rev),
+                None => self.name,
+            };
+            article.metadata.insert("draft".to_string(), draft);
+        }
+        if let Some(rev) = revision {
+            article.metadata.insert("revision".to_string(), rev);
+        }
+        if let Some(level) = self.std_level {
+            article
+                .metadata
+                .insert("status".to_string(), resource_slug(&level).to_string());
+        }
+        if let Some(group) = group {
+            article.metadata.insert("wg".to_string(), group.to_string());
+        }
+        if let Some(pages) = self.pages {
+            article
+                .metadata
+                .insert("pages".to_string(), pages.to_string());
+        }
+
+        article
+    }
+}
+
+#[async_trait]
+impl Collector for IetfCollector {
+    fn name(&self) -> &str {
+        "IETF"
+    }
+
+    fn description(&self) -> &str {
+        "Collects new RFCs and Internet-Draft revisions from the IETF Datatracker"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let mut group = None;
+        let mut doc_type = "draft,rfc";
+        let mut words = Vec::new();
+        for word in query.split_whitespace() {
+            if let Some(acronym) = word.strip_prefix("wg:") {
+                group = Some(acronym.to_lowercase());
+            } else if let Some(kind) = word.strip_prefix("type:") {
+                doc_type = kind;
+            } else {
+                words.push(word);
+            }
+        }
+        let title = words.join(" ");
+
+        let url = format!("{}/api/v1/doc/document/", self.base_url);
+        let limit = max_results.clamp(1, MAX_LIMIT).to_string();
+        let mut params = vec![
+            ("format", "json"),
+            ("type__in", doc_type),
+            ("order_by", "-time"),
+            ("limit", limit.as_str()),
+        ];
+        if let Some(group) = &group {
+            params.push(("group__acronym", group));
+        }
+        if !title.is_empty() {
+            params.push(("title__icontains", &title));
+        }
+
+        tracing::info!("Fetching from IETF Datatracker: {} ({:?})", url, params);
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&params)
+            .send()
+            .await?
+            .error_for_status()?;
+        let documents: DocumentList = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse IETF Datatracker response: {}", e);
+            format!("Failed to parse IETF Datatracker response: {}", e)
+        })?;
+
+        Ok(documents
+            .objects
+            .into_iter()
+            .take(max_results)
+            .map(|document| document.into_article(group.as_deref()))
+            .collect())
+    }
+}
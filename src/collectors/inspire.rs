@@ -0,0 +1,285 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://inspirehep.net/api";
+/// INSPIRE caps `size` at 1000, but large pages carry huge author lists for nothing
+const MAX_SIZE: usize = 100;
+/// Author lists longer than this are summarized
+const MAX_LISTED_AUTHORS: usize = 10;
+const FIELDS: &str = "titles,authors.full_name,author_count,collaborations,arxiv_eprints,\
+citation_count,dois,abstracts,earliest_date,publication_info";
+
+/// Collects high-energy physics literature from the INSPIRE-HEP API
+///
+/// The query uses INSPIRE's SPIRES-style syntax (`a witten and t anomaly`,
+/// `cn atlas and date > 2023`, `eprint 2401.00001`). Results are newest
+/// first; `sort:mostcited` orders them by citations instead. Author lists of
+/// large collaborations are shortened to the collaboration name.
+pub struct InspireHepCollector {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl InspireHepCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+}
+
+impl Default for InspireHepCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    hits: Hits,
+}
+
+#[derive(Debug, Deserialize)]
+struct Hits {
+    #[serde(default)]
+    hits: Vec<Hit>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Hit {
+    id: String,
+    metadata: Literature,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct Literature {
+    titles: Vec<Title>,
+    authors: Vec<Author>,
+    author_count: Option<usize>,
+    collaborations: Vec<Value>,
+    arxiv_eprints: Vec<Eprint>,
+    citation_count: Option<u64>,
+    dois: Vec<Value>,
+    abstracts: Vec<Value>,
+    earliest_date: Option<String>,
+    publication_info: Vec<PublicationInfo>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Title {
+    title: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Author {
+    full_name: String,
+}
+
+/// INSPIRE wraps most list entries as `{"value": ...}`
+#[derive(Debug, Deserialize)]
+struct Value {
+    value: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Eprint {
+    value: String,
+    #[serde(default)]
+    categories: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PublicationInfo {
+    journal_title: Option<String>,
+    journal_volume: Option<String>,
+    year: Option<u32>,
+}
+
+impl Literature {
+    /// Lists individual authors for small papers; for large ones, the
+    /// collaborations, or the first authors followed by "et al."
+    fn author_names(&self) -> Vec<String> {
+        let count = self.author_count.unwrap_or(self.authors.len());
+        if count <= MAX_LISTED_AUTHORS {
+            return self
+                .authors
+                .iter()
+                .map(|a| display_name(&a.full_name))
+                .collect();
+        }
+
+        if !self.collaborations.is_empty() {
+            return self
+                .collaborations
+                .iter()
+                .map(|c| {
+                    if c.value.to_lowercase().ends_with("collaboration") {
+                        c.value.clone()
+                    } else {
+                        format!("{} Collaboration", c.value)
+                    }
+                })
+                .collect();
+        }
+
+        self.authors
+            .iter()
+            .take(MAX_LISTED_AUTHORS)
+            .map(|a| display_name(&a.full_name))
+            .chain(std::iter::once("et al.".to_string()))
+            .collect()
+    }
+
+    fn journal(&self) -> Option<String> {
+        let info = self
+            .publication_info
+            .iter()
+            .find(|info| info.journal_title.is_some())?;
+        let mut journal = info.journal_title.clone()?;
+        if let Some(volume) = &info.journal_volume {
+            journal = format!("{} {}", journal, volume);
+        }
+        if let Some(year) = info.year {
+            journal = format!("{} ({})", journal, year);
+        }
+        Some(journal)
+    }
+}
+
+/// Turns INSPIRE's "Family, Given" into "Given Family"
+fn display_name(full_name: &str) -> String {
+    match full_name.split_once(", ") {
+        Some((family, given)) => format!("{} {}", given, family),
+        None => full_name.to_string(),
+    }
+}
+
+impl Hit {
+    fn into_article(self) -> Article {
+        let literature = self.metadata;
+        let authors = literature.author_names();
+        let journal = literature.journal();
+
+        let mut article = Article {
+            title: literature
+                .titles
+                .first()
+                .map(|t| t.title.clone())
+                .unwrap_or_default(),
+            authors,
+            url: format!("https://inspirehep.net/literature/{}", self.id),
+            published_date: literature.earliest_date.clone().unwrap_or_default(),
+            summary: literature
+                .abstracts
+                .first()
+                .map(|a| a.value.split_whitespace().collect::<Vec<_>>().join(" "))
+                .unwrap_or_default(),
+            source: "INSPIRE-HEP".to_string(),
+            ..Default::default()
+        };
+
+        if let Some(citations) = literature.citation_count {
+            article
+                .metadata
+                .insert("citations".to_string(), citations.to_string());
+        }
+        if !literature.collaborations.is_empty() {
+            let collaborations: Vec<&str> = literature
+                .collaborations
+                .iter()
+                .map(|c| c.value.as_str())
+                .collect();
+            article
+                .metadata
+                .insert("collaboration".to_string(), collaborations.join(", "));
+        }
+        if let Some(count) = literature
+            .author_count
+            .filter(|count| *count > MAX_LISTED_AUTHORS)
+        {
+            article
+                .metadata
+                .insert("author count".to_string(), count.to_string());
+        }
+        if let Some(eprint) = literature.arxiv_eprints.first() {
+            let arxiv = match eprint.categories.first() {
+                Some(category) => format!("{} [{}]", eprint.value, category),
+                None => eprint.value.clone(),
+            };
+            article.metadata.insert("arxiv".to_string(), arxiv);
+        }
+        if let Some(doi) = literature.dois.first() {
+            article
+                .metadata
+                .insert("doi".to_string(), doi.value.clone());
+        }
+        if let Some(journal) = journal {
+            article.metadata.insert("journal".to_string(), journal);
+        }
+
+        article
+    }
+}
+
+#[async_trait]
+impl Collector for InspireHepCollector {
+    fn name(&self) -> &str {
+        "INSPIRE-HEP"
+    }
+
+    fn description(&self) -> &str {
+        "Searches INSPIRE-HEP physics literature with SPIRES syntax, with citation counts"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let mut sort = "mostrecent";
+        let mut words = Vec::new();
+        for word in query.split_whitespace() {
+            match word.strip_prefix("sort:") {
+                Some(order) => sort = order,
+                None => words.push(word),
+            }
+        }
+        let search = words.join(" ");
+
+        let url = format!("{}/literature", self.base_url);
+        let size = max_results.clamp(1, MAX_SIZE).to_string();
+
+        tracing::info!("Fetching from INSPIRE-HEP: {} (query: {})", url, search);
+
+        let response = self
+            .client
+            .get(&url)
+            .header("Accept", "application/json")
+            .query(&[
+                ("q", search.as_str()),
+                ("sort", sort),
+                ("size", size.as_str()),
+                ("fields", FIELDS),
+            ])
+            .send()
+            .await?
+            .error_for_status()?;
+        let search: SearchResponse = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse INSPIRE-HEP response: {}", e);
+            format!("Failed to parse INSPIRE-HEP response: {}", e)
+        })?;
+
+        Ok(search
+            .hits
+            .hits
+            .into_iter()
+            .take(max_results)
+            .map(Hit::into_article)
+            .collect())
+    }
+}
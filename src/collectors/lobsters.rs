@@ -0,0 +1,274 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::html::to_plain_text;
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://lobste.rs";
+/// Lobsters serves 25 stories per page
+const PAGE_SIZE: usize = 25;
+/// Upper bound on pages fetched when filtering by keyword
+const MAX_PAGES: usize = 5;
+
+/// Collects stories from the Lobsters hottest, newest and tag listings
+///
+/// `tag:<name>` (repeatable, e.g. `tag:rust tag:plt`) reads the tag pages,
+/// which are ordered by hotness, `newest` switches from the hottest to the
+/// newest listing, and any other words must appear in a story's title.
+pub struct LobstersCollector {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl LobstersCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+}
+
+impl Default for LobstersCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Story {
+    short_id_url: String,
+    created_at: Option<String>,
+    title: String,
+    #[serde(default)]
+    url: String,
+    score: Option<i64>,
+    comment_count: Option<u64>,
+    #[serde(default)]
+    description: String,
+    comments_url: Option<String>,
+    submitter_user: Option<Submitter>,
+    #[serde(default)]
+    tags: Vec<String>,
+}
+
+/// Older Lobsters versions embed the whole user, newer ones just the name
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Submitter {
+    Name(String),
+    User { username: String },
+}
+
+impl Story {
+    fn into_article(self) -> Article {
+        let discussion_url = self.comments_url.unwrap_or(self.short_id_url);
+        let author = self.submitter_user.map(|submitter| match submitter {
+            Submitter::Name(name) => name,
+            Submitter::User { username } => username,
+        });
+
+        let mut article = Article {
+            title: self.title,
+            authors: author.into_iter().collect(),
+            // Text posts have no external link
+            url: if self.url.is_empty() {
+                discussion_url.clone()
+            } else {
+                self.url
+            },
+            published_date: self.created_at.unwrap_or_default(),
+            summary: to_plain_text(&self.description),
+            source: "Lobsters".to_string(),
+            ..Default::default()
+        };
+
+        if let Some(score) = self.score {
+            article
+                .metadata
+                .insert("score".to_string(), score.to_string());
+        }
+        if let Some(comments) = self.comment_count {
+            article
+                .metadata
+                .insert("comments".to_string(), comments.to_string());
+        }
+        if !self.tags.is_empty() {
+            article
+                .metadata
+                .insert("tags".to_string(), self.tags.join(", "));
+        }
+        article
+            .metadata
+            .insert("discussion".to_string(), discussion_url);
+
+        article
+    }
+}
+
+/// Tags, ordering and title keywords parsed from the query
+struct LobstersQuery {
+    tags: Vec<String>,
+    newest: bool,
+    keywords: Vec<String>,
+}
+
+impl LobstersQuery {
+    fn parse(query: &str) -> CollectorResult<Self> {
+        let mut parsed = Self {
+            tags: Vec::new(),
+            newest: false,
+            keywords: Vec::new(),
+        };
+
+        for word in query.split_whitespace() {
+            if let Some(tag) = word.strip_prefix("tag:") {
+                parsed.tags.push(tag.to_lowercase());
+            } else if word == "newest" {
+                parsed.newest = true;
+            } else if word != "hottest" {
+                parsed.keywords.push(word.to_lowercase());
+            }
+        }
+
+        if parsed.newest && !parsed.tags.is_empty() {
+            return Err(
+                "Lobsters tag pages have no newest ordering, drop `newest` or the tags".into(),
+            );
+        }
+
+        Ok(parsed)
+    }
+
+    /// Path of the listing, empty for the hottest stories
+    ///
+    /// Page 1 of each listing lives at its root, later pages under `/page/N`.
+    fn listing(&self) -> String {
+        if !self.tags.is_empty() {
+            format!("/t/{}", self.tags.join(","))
+        } else if self.newest {
+            "/newest".to_string()
+        } else {
+            String::new()
+        }
+    }
+}
+
+#[async_trait]
+impl Collector for LobstersCollector {
+    fn name(&self) -> &str {
+        "Lobsters"
+    }
+
+    fn description(&self) -> &str {
+        "Collects Lobsters stories from the hottest, newest or tag listings"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let query = LobstersQuery::parse(query)?;
+        let listing = query.listing();
+
+        let mut articles = Vec::new();
+        for page in 1..=MAX_PAGES {
+            let url = match (page, listing.is_empty()) {
+                (1, true) => format!("{}/hottest.json", self.base_url),
+                (1, false) => format!("{}{}.json", self.base_url, listing),
+                _ => format!("{}{}/page/{}.json", self.base_url, listing, page),
+            };
+
+            tracing::info!("Fetching from Lobsters: {}", url);
+
+            let response = self.client.get(&url).send().await?.error_for_status()?;
+            let stories: Vec<Story> = response.json().await.map_err(|e| {
+                tracing::error!("Failed to parse Lobsters response: {}", e);
+                format!("Failed to parse Lobsters response: {}", e)
+            })?;
+
+            let last_page = stories.len() < PAGE_SIZE;
+            articles.extend(
+                stories
+                    .into_iter()
+                    .filter(|story| {
+                        let title = story.title.to_lowercase();
+                        query.keywords.iter().all(|keyword| title.contains(keyword))
+                    })
+                    .map(Story::into_article),
+            );
+
+            if last_page || articles.len() >= max_results {
+                break;
+            }
+        }
+
+        articles.truncate(max_results);
+        Ok(articles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_selects_listing_and_keywords() {
+        let query = LobstersQuery::parse("tag:Rust tag:plt Async closures").unwrap();
+        assert_eq!(query.listing(), "/t/rust,plt");
+        assert_eq!(query.keywords, vec!["async", "closures"]);
+
+        assert_eq!(LobstersQuery::parse("newest").unwrap().listing(), "/newest");
+        assert_eq!(LobstersQuery::parse("hottest").unwrap().listing(), "");
+        assert!(LobstersQuery::parse("hottest").unwrap().keywords.is_empty());
+        assert!(LobstersQuery::parse("tag:rust newest").is_err());
+    }
+
+    #[test]
+    fn link_story_becomes_article() {
+        let story: Story = serde_json::from_value(serde_json::json!({
+            "short_id_url": "https://lobste.rs/s/s5",
+            "created_at": "2025-06-01T10:00:00.000-05:00",
+            "title": "Async Rust",
+            "url": "https://example.com/5",
+            "score": 5,
+            "comment_count": 3,
+            "comments_url": "https://lobste.rs/s/s5/async_rust",
+            "submitter_user": "alice",
+            "tags": ["rust", "plt"]
+        }))
+        .unwrap();
+        let article = story.into_article();
+
+        assert_eq!(article.url, "https://example.com/5");
+        assert_eq!(article.authors, vec!["alice"]);
+        assert_eq!(article.published_date, "2025-06-01T10:00:00.000-05:00");
+        assert_eq!(article.metadata["score"], "5");
+        assert_eq!(article.metadata["comments"], "3");
+        assert_eq!(article.metadata["tags"], "rust, plt");
+        assert_eq!(
+            article.metadata["discussion"],
+            "https://lobste.rs/s/s5/async_rust"
+        );
+    }
+
+    #[test]
+    fn text_story_links_to_its_discussion() {
+        let story: Story = serde_json::from_value(serde_json::json!({
+            "short_id_url": "https://lobste.rs/s/s26",
+            "title": "Async closures in Rust",
+            "url": "",
+            "description": "<p>What do you think?</p>",
+            "submitter_user": {"username": "bob"}
+        }))
+        .unwrap();
+        let article = story.into_article();
+
+        assert_eq!(article.url, "https://lobste.rs/s/s26");
+        assert_eq!(article.authors, vec!["bob"]);
+        assert_eq!(article.summary, "What do you think?");
+        assert!(!article.metadata.contains_key("tags"));
+    }
+}
@@ -0,0 +1,303 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use scraper::{Html, Selector};
+use serde::Deserialize;
+use std::collections::HashSet;
+
+use super::html::to_plain_text;
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_INSTANCE: &str = "https://mastodon.social";
+/// Mastodon caps `limit` at 40 statuses per timeline request
+const PAGE_LIMIT: &str = "40";
+
+/// Collects articles and papers linked from public Mastodon posts
+///
+/// `#<tag>` reads a hashtag timeline and `@<user>` (or `@user@host`) an
+/// account's posts, both repeatable; without either, the configured default
+/// hashtags are read, or the query words joined into one hashtag
+/// (`machine learning` → `#machinelearning`). Remaining words filter the
+/// posts. Each post becomes an article for the page it links to, and
+/// boosts of an already seen post or link are dropped.
+pub struct MastodonCollector {
+    client: reqwest::Client,
+    base_url: String,
+    access_token: Option<String>,
+    hashtags: Vec<String>,
+}
+
+impl MastodonCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_INSTANCE.to_string(),
+            access_token: None,
+            hashtags: Vec::new(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets an access token, for instances that restrict public timelines
+    pub fn with_access_token(mut self, access_token: impl Into<String>) -> Self {
+        self.access_token = Some(access_token.into());
+        self
+    }
+
+    /// Sets the hashtags read when the query names no hashtag or account
+    pub fn with_hashtags(mut self, hashtags: Vec<String>) -> Self {
+        self.hashtags = hashtags;
+        self
+    }
+
+    async fn get<T: for<'de> Deserialize<'de>>(
+        &self,
+        url: &str,
+        params: &[(&str, &str)],
+    ) -> CollectorResult<T> {
+        tracing::info!("Fetching from Mastodon: {} ({:?})", url, params);
+
+        let mut request = self.client.get(url).query(params);
+        if let Some(access_token) = &self.access_token {
+            request = request.bearer_auth(access_token);
+        }
+        let response = request.send().await?.error_for_status()?;
+        response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse Mastodon response: {}", e);
+            format!("Failed to parse Mastodon response: {}", e).into()
+        })
+    }
+
+    async fn hashtag_timeline(&self, tag: &str) -> CollectorResult<Vec<Status>> {
+        let url = format!("{}/api/v1/timelines/tag/{}", self.base_url, tag);
+        self.get(&url, &[("limit", PAGE_LIMIT)]).await
+    }
+
+    async fn account_statuses(&self, acct: &str) -> CollectorResult<Vec<Status>> {
+        let lookup = format!("{}/api/v1/accounts/lookup", self.base_url);
+        let account: Account = self.get(&lookup, &[("acct", acct)]).await?;
+
+        let url = format!("{}/api/v1/accounts/{}/statuses", self.base_url, account.id);
+        self.get(&url, &[("limit", PAGE_LIMIT), ("exclude_replies", "true")])
+            .await
+    }
+}
+
+impl Default for MastodonCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct Account {
+    id: String,
+    acct: String,
+    #[serde(default)]
+    display_name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Status {
+    /// Federation-wide id, shared by every copy of a post
+    uri: String,
+    url: Option<String>,
+    created_at: String,
+    /// HTML
+    #[serde(default)]
+    content: String,
+    account: Account,
+    reblog: Option<Box<Status>>,
+    card: Option<Card>,
+    #[serde(default)]
+    reblogs_count: u64,
+    #[serde(default)]
+    favourites_count: u64,
+}
+
+/// Link preview generated by the instance
+#[derive(Debug, Default, Deserialize)]
+struct Card {
+    url: String,
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    author_name: String,
+    #[serde(default)]
+    provider_name: String,
+}
+
+impl Status {
+    /// The first link in the post that is not a mention or hashtag
+    fn content_link(&self) -> Option<String> {
+        let links = Selector::parse("a[href]:not(.mention)").expect("valid selector");
+        Html::parse_fragment(&self.content)
+            .select(&links)
+            .filter_map(|link| link.value().attr("href"))
+            .find(|href| href.starts_with("http"))
+            .map(String::from)
+    }
+
+    /// Turns the post into an article for the page it links to, if any
+    fn into_article(self) -> Option<Article> {
+        let link = self.card.as_ref().map(|card| card.url.clone());
+        let url = link.or_else(|| self.content_link())?;
+        let text = to_plain_text(&self.content);
+        let card = self.card.unwrap_or_default();
+
+        let mut article = Article {
+            title: if card.title.is_empty() {
+                url.clone()
+            } else {
+                card.title
+            },
+            authors: (!card.author_name.is_empty())
+                .then_some(card.author_name)
+                .into_iter()
+                .collect(),
+            url,
+            published_date: DateTime::parse_from_rfc3339(&self.created_at)
+                .map(|date| date.to_rfc3339())
+                .unwrap_or(self.created_at),
+            summary: if text.is_empty() {
+                card.description
+            } else {
+                text
+            },
+            source: "Mastodon".to_string(),
+            ..Default::default()
+        };
+
+        let poster = if self.account.display_name.is_empty() {
+            format!("@{}", self.account.acct)
+        } else {
+            format!("{} (@{})", self.account.display_name, self.account.acct)
+        };
+        article.metadata.insert("posted by".to_string(), poster);
+        article
+            .metadata
+            .insert("post".to_string(), self.url.unwrap_or(self.uri));
+        article
+            .metadata
+            .insert("boosts".to_string(), self.reblogs_count.to_string());
+        article
+            .metadata
+            .insert("favourites".to_string(), self.favourites_count.to_string());
+        if !card.provider_name.is_empty() {
+            article
+                .metadata
+                .insert("site".to_string(), card.provider_name);
+        }
+
+        Some(article)
+    }
+}
+
+/// Letters (in any script), digits and underscores, as Mastodon allows in hashtags
+fn is_hashtag_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[async_trait]
+impl Collector for MastodonCollector {
+    fn name(&self) -> &str {
+        "Mastodon"
+    }
+
+    fn description(&self) -> &str {
+        "Collects articles and papers linked from Mastodon hashtags and accounts"
+    }
+
+    fn default_query(&self) -> &str {
+        // The configured hashtags are read when the query names none
+        if self.hashtags.is_empty() {
+            "#machinelearning"
+        } else {
+            ""
+        }
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let mut hashtags = Vec::new();
+        let mut accounts = Vec::new();
+        let mut words = Vec::new();
+        for word in query.split_whitespace() {
+            if let Some(tag) = word.strip_prefix('#') {
+                hashtags.push(tag.to_string());
+            } else if let Some(acct) = word.strip_prefix('@') {
+                accounts.push(acct.to_string());
+            } else {
+                words.push(word.to_lowercase());
+            }
+        }
+        if hashtags.is_empty() && accounts.is_empty() {
+            if !self.hashtags.is_empty() {
+                hashtags = self.hashtags.clone();
+            } else if words.iter().any(|word| word.chars().any(is_hashtag_char)) {
+                hashtags.push(
+                    words
+                        .concat()
+                        .chars()
+                        .filter(|&c| is_hashtag_char(c))
+                        .collect(),
+                );
+                words.clear();
+            } else {
+                return Err("Mastodon needs a #hashtag, an @account or keywords".into());
+            }
+        }
+
+        // Hashtags go into the URL path, so only hashtag characters get through
+        let hashtags: Vec<&str> = hashtags
+            .iter()
+            .map(|tag| tag.trim_start_matches('#'))
+            .collect();
+        if let Some(tag) = hashtags
+            .iter()
+            .find(|tag| tag.is_empty() || !tag.chars().all(is_hashtag_char))
+        {
+            return Err(format!("Invalid Mastodon hashtag: #{}", tag).into());
+        }
+
+        let mut statuses = Vec::new();
+        for tag in hashtags {
+            statuses.extend(self.hashtag_timeline(tag).await?);
+        }
+        for acct in &accounts {
+            statuses.extend(self.account_statuses(acct).await?);
+        }
+
+        // Boosts stand in for the boosted post, so repeats collapse onto it
+        let mut statuses: Vec<Status> = statuses
+            .into_iter()
+            .map(|status| match status.reblog {
+                Some(original) => *original,
+                None => status,
+            })
+            .collect();
+        statuses.sort_by_key(|status| {
+            std::cmp::Reverse(DateTime::parse_from_rfc3339(&status.created_at).ok())
+        });
+
+        let mut seen_posts = HashSet::new();
+        let mut seen_links = HashSet::new();
+        let articles = statuses
+            .into_iter()
+            .filter(|status| seen_posts.insert(status.uri.clone()))
+            .filter(|status| {
+                let text = to_plain_text(&status.content).to_lowercase();
+                words.iter().all(|word| text.contains(word))
+            })
+            .filter_map(Status::into_article)
+            .filter(|article| seen_links.insert(article.url.clone()))
+            .take(max_results)
+            .collect();
+
+        Ok(articles)
+    }
+}
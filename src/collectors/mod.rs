@@ -1,14 +1,78 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
 
+pub mod acl_anthology;
+pub mod ads;
 pub mod arxiv;
+pub mod biorxiv;
+pub mod bluesky;
+pub mod core;
+pub mod crossref;
+pub mod dblp;
+pub mod devto;
+pub mod europe_pmc;
 pub mod example;
+pub mod feed;
+pub mod github;
+pub mod hackernews;
+mod html;
+pub mod huggingface;
+pub mod ietf;
+pub mod inspire;
+pub mod lobsters;
+pub mod mastodon;
+pub mod openalex;
+pub mod openreview;
+pub mod pubmed;
+pub mod reddit;
+pub mod scrape;
+pub mod semantic_scholar;
+pub mod stackexchange;
+pub mod zenodo;
 
+pub use self::core::CoreCollector;
+pub use acl_anthology::AclAnthologyCollector;
+pub use ads::AdsCollector;
 pub use arxiv::ArxivCollector;
+pub use biorxiv::BioRxivCollector;
+pub use bluesky::BlueskyCollector;
+pub use crossref::CrossrefCollector;
+pub use dblp::DblpCollector;
+pub use devto::DevToCollector;
+pub use europe_pmc::EuropePmcCollector;
 pub use example::ExampleArticleCollector;
+pub use feed::FeedCollector;
+pub use github::GitHubCollector;
+pub use hackernews::HackerNewsCollector;
+pub use huggingface::HuggingFacePapersCollector;
+pub use ietf::IetfCollector;
+pub use inspire::InspireHepCollector;
+pub use lobsters::LobstersCollector;
+pub use mastodon::MastodonCollector;
+pub use openalex::OpenAlexCollector;
+pub use openreview::OpenReviewCollector;
+pub use pubmed::PubMedCollector;
+pub use reddit::RedditCollector;
+pub use scrape::{ScrapeCollector, ScrapeConfig};
+pub use semantic_scholar::SemanticScholarCollector;
+pub use stackexchange::StackExchangeCollector;
+pub use zenodo::ZenodoCollector;
+
+/// Upper bound on a single request, so one stalled source cannot hang a command
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Builds the HTTP client collectors use, with `REQUEST_TIMEOUT` applied
+pub(crate) fn http_client() -> reqwest::Client {
+    reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()
+        .expect("failed to build HTTP client")
+}
 
 /// Represents a collected article/paper
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Article {
     pub title: String,
     pub authors: Vec<String>,
@@ -16,6 +80,9 @@ pub struct Article {
     pub published_date: String,
     pub summary: String,
     pub source: String,
+    /// Source-specific details (venue, year, ...) shown alongside the article
+    #[serde(default)]
+    pub metadata: BTreeMap<String, String>,
 }
 
 /// Result type for collection operations
@@ -33,4 +100,13 @@ pub trait Collector: Send + Sync {
 
     /// Returns a description of what this collector does
     fn description(&self) -> &str;
+
+    /// Returns the query used when none is given (scheduled runs, `/collect`
+    /// without a query)
+    ///
+    /// The empty default suits collectors that list recent items on their
+    /// own; search-only collectors override it with a query their API accepts.
+    fn default_query(&self) -> &str {
+        ""
+    }
 }
@@ -0,0 +1,232 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://api.openalex.org";
+/// OpenAlex caps `per-page` at 200
+const MAX_PER_PAGE: usize = 200;
+
+/// Collects journal articles, conference papers and preprints from OpenAlex
+///
+/// Query words of the form `field:value` (e.g. `concepts.id:C41008148` or
+/// `from_publication_date:2024-01-01`) are passed through as OpenAlex
+/// filters; the remaining words become the full-text `search`.
+pub struct OpenAlexCollector {
+    client: reqwest::Client,
+    base_url: String,
+    mailto: Option<String>,
+}
+
+impl OpenAlexCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            mailto: None,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the contact email that puts requests into OpenAlex's polite pool
+    pub fn with_mailto(mut self, mailto: impl Into<String>) -> Self {
+        self.mailto = Some(mailto.into());
+        self
+    }
+}
+
+impl Default for OpenAlexCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct WorksResponse {
+    #[serde(default)]
+    results: Vec<Work>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Work {
+    id: String,
+    doi: Option<String>,
+    display_name: Option<String>,
+    publication_date: Option<String>,
+    publication_year: Option<i32>,
+    #[serde(rename = "type")]
+    work_type: Option<String>,
+    cited_by_count: Option<u64>,
+    #[serde(default)]
+    authorships: Vec<Authorship>,
+    primary_location: Option<Location>,
+    abstract_inverted_index: Option<HashMap<String, Vec<usize>>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Authorship {
+    author: AuthorshipAuthor,
+}
+
+#[derive(Debug, Deserialize)]
+struct AuthorshipAuthor {
+    display_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Location {
+    source: Option<LocationSource>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LocationSource {
+    display_name: Option<String>,
+}
+
+impl Work {
+    fn into_article(self) -> Article {
+        let summary = self
+            .abstract_inverted_index
+            .as_ref()
+            .map(reconstruct_abstract)
+            .unwrap_or_default();
+
+        let mut article = Article {
+            title: self
+                .display_name
+                .unwrap_or_default()
+                .trim()
+                .replace('\n', " "),
+            authors: self
+                .authorships
+                .into_iter()
+                .filter_map(|a| a.author.display_name)
+                .collect(),
+            url: self.doi.unwrap_or(self.id),
+            published_date: self
+                .publication_date
+                .or_else(|| self.publication_year.map(|y| y.to_string()))
+                .unwrap_or_default(),
+            summary,
+            source: "OpenAlex".to_string(),
+            ..Default::default()
+        };
+
+        let venue = self
+            .primary_location
+            .and_then(|l| l.source)
+            .and_then(|s| s.display_name);
+        if let Some(venue) = venue {
+            article.metadata.insert("venue".to_string(), venue);
+        }
+        if let Some(year) = self.publication_year {
+            article
+                .metadata
+                .insert("year".to_string(), year.to_string());
+        }
+        if let Some(work_type) = self.work_type {
+            article.metadata.insert("type".to_string(), work_type);
+        }
+        if let Some(cited_by) = self.cited_by_count {
+            article
+                .metadata
+                .insert("citations".to_string(), cited_by.to_string());
+        }
+
+        article
+    }
+}
+
+/// Rebuilds plain text from OpenAlex's `word -> [positions]` abstract index
+fn reconstruct_abstract(index: &HashMap<String, Vec<usize>>) -> String {
+    let mut positioned: Vec<(usize, &str)> = index
+        .iter()
+        .flat_map(|(word, positions)| positions.iter().map(move |&p| (p, word.as_str())))
+        .collect();
+    positioned.sort_unstable_by_key(|&(position, _)| position);
+
+    positioned
+        .into_iter()
+        .map(|(_, word)| word)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Splits a query into OpenAlex `filter` and `search` parameters
+fn split_query(query: &str) -> (String, String) {
+    let (filters, words): (Vec<&str>, Vec<&str>) =
+        query.split_whitespace().partition(|word| is_filter(word));
+    (filters.join(","), words.join(" "))
+}
+
+fn is_filter(word: &str) -> bool {
+    match word.split_once(':') {
+        Some((field, value)) => {
+            !field.is_empty()
+                && !value.is_empty()
+                && field
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c == '_' || c == '.')
+        }
+        None => false,
+    }
+}
+
+#[async_trait]
+impl Collector for OpenAlexCollector {
+    fn name(&self) -> &str {
+        "OpenAlex"
+    }
+
+    fn description(&self) -> &str {
+        "Collects journal articles, conference papers and preprints from OpenAlex"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let url = format!("{}/works", self.base_url);
+        let (filter, search) = split_query(query);
+        let per_page = max_results.clamp(1, MAX_PER_PAGE).to_string();
+
+        tracing::info!(
+            "Fetching from OpenAlex: {} (search: {}, filter: {})",
+            url,
+            search,
+            filter
+        );
+
+        let mut params = vec![("per-page", per_page.as_str())];
+        if !search.is_empty() {
+            params.push(("search", search.as_str()));
+        }
+        if !filter.is_empty() {
+            params.push(("filter", filter.as_str()));
+        }
+        if let Some(mailto) = &self.mailto {
+            params.push(("mailto", mailto.as_str()));
+        }
+
+        let response = self
+            .client
+            .get(&url)
+            .query(&params)
+            .send()
+            .await?
+            .error_for_status()?;
+        let works: WorksResponse = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse OpenAlex response: {}", e);
+            format!("Failed to parse OpenAlex response: {}", e)
+        })?;
+
+        Ok(works
+            .results
+            .into_iter()
+            .take(max_results)
+            .map(Work::into_article)
+            .collect())
+    }
+}
@@ -0,0 +1,281 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use serde::Deserialize;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://api2.openreview.net";
+const PAGE_SIZE: usize = 200;
+/// Upper bound on pages scanned when filtering a venue's submissions by keyword
+const MAX_PAGES: usize = 10;
+
+/// Collects submissions and accepted papers from OpenReview (API v2)
+///
+/// A query word that looks like a venue id (`ICLR.cc/2026/Conference`, or
+/// `venue:<id>`) lists that venue's submissions, with any other words used
+/// as keywords over title, abstract and keywords. Without a venue id the
+/// query goes to OpenReview's full-text search.
+pub struct OpenReviewCollector {
+    client: reqwest::Client,
+    base_url: String,
+}
+
+impl OpenReviewCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    async fn fetch_notes(&self, path: &str, params: &[(&str, &str)]) -> CollectorResult<Vec<Note>> {
+        let url = format!("{}/{}", self.base_url, path);
+
+        tracing::info!("Fetching from OpenReview: {} ({:?})", url, params);
+
+        let response = self
+            .client
+            .get(&url)
+            .query(params)
+            .send()
+            .await?
+            .error_for_status()?;
+        let notes: NotesResponse = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse OpenReview response: {}", e);
+            format!("Failed to parse OpenReview response: {}", e)
+        })?;
+
+        Ok(notes.notes)
+    }
+
+    async fn venue_submissions(
+        &self,
+        venue_id: &str,
+        keywords: &[String],
+        max_results: usize,
+    ) -> CollectorResult<Vec<Article>> {
+        let invitation = format!("{}/-/Submission", venue_id);
+        let limit = PAGE_SIZE.to_string();
+        let mut articles = Vec::new();
+
+        for page in 0..MAX_PAGES {
+            let offset = (page * PAGE_SIZE).to_string();
+            let notes = self
+                .fetch_notes(
+                    "notes",
+                    &[
+                        ("invitation", invitation.as_str()),
+                        ("details", "directReplies"),
+                        ("limit", limit.as_str()),
+                        ("offset", offset.as_str()),
+                    ],
+                )
+                .await?;
+
+            let last_page = notes.len() < PAGE_SIZE;
+            articles.extend(
+                notes
+                    .into_iter()
+                    .filter(|note| note.matches(keywords))
+                    .map(Note::into_article),
+            );
+
+            if last_page || articles.len() >= max_results {
+                break;
+            }
+        }
+
+        articles.truncate(max_results);
+        Ok(articles)
+    }
+}
+
+impl Default for OpenReviewCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct NotesResponse {
+    #[serde(default)]
+    notes: Vec<Note>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Note {
+    forum: String,
+    cdate: Option<i64>,
+    pdate: Option<i64>,
+    content: NoteContent,
+    details: Option<NoteDetails>,
+}
+
+/// OpenReview v2 wraps every content field as `{"value": ...}`
+#[derive(Debug, Deserialize)]
+struct Field<T> {
+    value: T,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+struct NoteContent {
+    title: Option<Field<String>>,
+    authors: Option<Field<Vec<String>>>,
+    #[serde(rename = "abstract")]
+    abstract_text: Option<Field<String>>,
+    keywords: Option<Field<Vec<String>>>,
+    venue: Option<Field<String>>,
+    decision: Option<Field<String>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct NoteDetails {
+    #[serde(default)]
+    direct_replies: Vec<Reply>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Reply {
+    #[serde(default)]
+    invitations: Vec<String>,
+    #[serde(default)]
+    content: NoteContent,
+}
+
+impl Note {
+    fn matches(&self, keywords: &[String]) -> bool {
+        let content = &self.content;
+        let text = [
+            content.title.as_ref().map(|f| f.value.as_str()),
+            content.abstract_text.as_ref().map(|f| f.value.as_str()),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(
+            content
+                .keywords
+                .iter()
+                .flat_map(|f| f.value.iter().map(String::as_str)),
+        )
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase();
+
+        keywords.iter().all(|keyword| text.contains(keyword))
+    }
+
+    /// The decision reply if one is visible, otherwise the venue string
+    /// ("ICLR 2026 Poster", "Submitted to ICLR 2026", ...)
+    fn status(&self) -> Option<String> {
+        let decision = self
+            .details
+            .iter()
+            .flat_map(|details| &details.direct_replies)
+            .filter(|reply| reply.invitations.iter().any(|i| i.ends_with("/Decision")))
+            .find_map(|reply| reply.content.decision.as_ref())
+            .map(|field| field.value.clone());
+
+        decision.or_else(|| self.content.venue.as_ref().map(|f| f.value.clone()))
+    }
+
+    fn into_article(self) -> Article {
+        let status = self.status();
+        let published_date = self
+            .pdate
+            .or(self.cdate)
+            .and_then(DateTime::from_timestamp_millis)
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_default();
+        let content = self.content;
+
+        let mut article = Article {
+            title: content.title.map(|f| f.value).unwrap_or_default(),
+            authors: content.authors.map(|f| f.value).unwrap_or_default(),
+            url: format!("https://openreview.net/forum?id={}", self.forum),
+            published_date,
+            summary: content
+                .abstract_text
+                .map(|f| f.value.trim().replace('\n', " "))
+                .unwrap_or_default(),
+            source: "OpenReview".to_string(),
+            ..Default::default()
+        };
+
+        if let Some(status) = status {
+            article.metadata.insert("status".to_string(), status);
+        }
+        if let Some(keywords) = content.keywords.filter(|f| !f.value.is_empty()) {
+            article
+                .metadata
+                .insert("keywords".to_string(), keywords.value.join(", "));
+        }
+
+        article
+    }
+}
+
+/// Venue ids look like `ICLR.cc/2026/Conference` or `NeurIPS.cc/2025/Workshop/XYZ`
+fn venue_id(word: &str) -> Option<&str> {
+    if let Some(venue) = word.strip_prefix("venue:") {
+        return Some(venue);
+    }
+    let first = word.split('/').next()?;
+    (word.contains('/') && first.contains('.')).then_some(word)
+}
+
+#[async_trait]
+impl Collector for OpenReviewCollector {
+    fn name(&self) -> &str {
+        "OpenReview"
+    }
+
+    fn description(&self) -> &str {
+        "Collects conference submissions and decisions from OpenReview by venue id"
+    }
+
+    fn default_query(&self) -> &str {
+        "artificial intelligence"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let mut venue = None;
+        let mut keywords = Vec::new();
+        for word in query.split_whitespace() {
+            match venue_id(word) {
+                Some(id) => venue = Some(id),
+                None => keywords.push(word.to_lowercase()),
+            }
+        }
+
+        if let Some(venue) = venue {
+            return self.venue_submissions(venue, &keywords, max_results).await;
+        }
+
+        let limit = max_results.max(1).to_string();
+        let notes = self
+            .fetch_notes(
+                "notes/search",
+                &[
+                    ("term", query),
+                    ("type", "terms"),
+                    ("content", "all"),
+                    ("source", "forum"),
+                    ("limit", limit.as_str()),
+                ],
+            )
+            .await?;
+
+        Ok(notes
+            .into_iter()
+            .take(max_results)
+            .map(Note::into_article)
+            .collect())
+    }
+}
@@ -0,0 +1,397 @@
+use async_trait::async_trait;
+use quick_xml::de::from_str;
+use serde::Deserialize;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://eutils.ncbi.nlm.nih.gov/entrez/eutils";
+/// NCBI allows 3 requests per second without an API key and 10 with one
+const MIN_INTERVAL: Duration = Duration::from_millis(334);
+const MIN_INTERVAL_WITH_KEY: Duration = Duration::from_millis(100);
+/// Inline formatting tags PubMed allows inside titles and abstracts
+const INLINE_TAGS: [&str; 5] = ["i", "b", "u", "sup", "sub"];
+
+/// Collects biomedical literature from PubMed via NCBI E-utilities
+///
+/// Runs `esearch` to find matching PMIDs and `efetch` to retrieve the
+/// records, spacing requests to stay within NCBI's rate limit.
+pub struct PubMedCollector {
+    client: reqwest::Client,
+    base_url: String,
+    api_key: Option<String>,
+    tool: Option<String>,
+    email: Option<String>,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl PubMedCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            api_key: None,
+            tool: None,
+            email: None,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the NCBI API key, raising the rate limit to 10 requests per second
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+
+    /// Sets the `tool` parameter NCBI uses to identify the application
+    pub fn with_tool(mut self, tool: impl Into<String>) -> Self {
+        self.tool = Some(tool.into());
+        self
+    }
+
+    /// Sets the `email` parameter NCBI uses to contact the developer
+    pub fn with_email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// Sends a GET request to an E-utility once the rate limit allows it
+    async fn get(&self, utility: &str, params: &[(&str, &str)]) -> CollectorResult<String> {
+        let interval = if self.api_key.is_some() {
+            MIN_INTERVAL_WITH_KEY
+        } else {
+            MIN_INTERVAL
+        };
+
+        {
+            let mut last_request = self.last_request.lock().await;
+            if let Some(last) = *last_request {
+                tokio::time::sleep_until(last + interval).await;
+            }
+            *last_request = Some(Instant::now());
+        }
+
+        let url = format!("{}/{}", self.base_url, utility);
+        tracing::info!("Fetching from PubMed: {}", url);
+
+        let mut request = self.client.get(&url).query(params);
+        for (key, value) in [
+            ("api_key", &self.api_key),
+            ("tool", &self.tool),
+            ("email", &self.email),
+        ] {
+            if let Some(value) = value {
+                request = request.query(&[(key, value)]);
+            }
+        }
+
+        let response = request.send().await?.error_for_status()?;
+        Ok(response.text().await?)
+    }
+}
+
+impl Default for PubMedCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ESearchResult {
+    #[serde(default)]
+    id_list: IdList,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct IdList {
+    #[serde(rename = "Id", default)]
+    ids: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PubmedArticleSet {
+    #[serde(rename = "PubmedArticle", default)]
+    articles: Vec<PubmedArticle>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PubmedArticle {
+    medline_citation: MedlineCitation,
+    pubmed_data: Option<PubmedData>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct MedlineCitation {
+    #[serde(rename = "PMID")]
+    pmid: Pmid,
+    article: PubmedArticleDetails,
+}
+
+#[derive(Debug, Deserialize)]
+struct Pmid {
+    #[serde(rename = "$text")]
+    value: String,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PubmedArticleDetails {
+    journal: Journal,
+    article_title: String,
+    #[serde(rename = "Abstract")]
+    abstract_section: Option<AbstractSection>,
+    author_list: Option<AuthorList>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Journal {
+    title: Option<String>,
+    journal_issue: JournalIssue,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct JournalIssue {
+    pub_date: PubDate,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PubDate {
+    year: Option<String>,
+    month: Option<String>,
+    day: Option<String>,
+    medline_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AbstractSection {
+    #[serde(rename = "AbstractText", default)]
+    texts: Vec<AbstractText>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AbstractText {
+    #[serde(rename = "@Label")]
+    label: Option<String>,
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct AuthorList {
+    #[serde(rename = "Author", default)]
+    authors: Vec<PubmedAuthor>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PubmedAuthor {
+    last_name: Option<String>,
+    fore_name: Option<String>,
+    collective_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct PubmedData {
+    article_id_list: Option<ArticleIdList>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ArticleIdList {
+    #[serde(rename = "ArticleId", default)]
+    ids: Vec<ArticleId>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ArticleId {
+    #[serde(rename = "@IdType")]
+    id_type: String,
+    #[serde(rename = "$text")]
+    value: String,
+}
+
+impl PubDate {
+    fn format(self) -> String {
+        match self.medline_date {
+            Some(date) => date,
+            None => [self.year, self.month, self.day]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(" "),
+        }
+    }
+}
+
+impl PubmedAuthor {
+    fn display_name(self) -> Option<String> {
+        match (self.fore_name, self.last_name, self.collective_name) {
+            (Some(fore), Some(last), _) => Some(format!("{} {}", fore, last)),
+            (None, Some(last), _) => Some(last),
+            (_, None, collective) => collective,
+        }
+    }
+}
+
+impl PubmedArticle {
+    fn into_article(self) -> Article {
+        let pmid = self.medline_citation.pmid.value.trim().to_string();
+        let details = self.medline_citation.article;
+
+        let summary = details
+            .abstract_section
+            .map(|section| {
+                section
+                    .texts
+                    .into_iter()
+                    .map(|t| match t.label {
+                        Some(label) => format!("{}: {}", label, t.text.trim()),
+                        None => t.text.trim().to_string(),
+                    })
+                    .collect::<Vec<_>>()
+                    .join(" ")
+            })
+            .unwrap_or_default();
+
+        let mut article = Article {
+            title: details.article_title.trim().replace('\n', " "),
+            authors: details
+                .author_list
+                .map(|list| {
+                    list.authors
+                        .into_iter()
+                        .filter_map(PubmedAuthor::display_name)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            url: format!("https://pubmed.ncbi.nlm.nih.gov/{}/", pmid),
+            published_date: details.journal.journal_issue.pub_date.format(),
+            summary: summary.replace('\n', " "),
+            source: "PubMed".to_string(),
+            ..Default::default()
+        };
+
+        let doi = self
+            .pubmed_data
+            .and_then(|data| data.article_id_list)
+            .and_then(|list| list.ids.into_iter().find(|id| id.id_type == "doi"));
+        if let Some(doi) = doi {
+            article.metadata.insert("doi".to_string(), doi.value);
+        }
+        if let Some(journal) = details.journal.title {
+            article.metadata.insert("journal".to_string(), journal);
+        }
+        article.metadata.insert("pmid".to_string(), pmid);
+
+        article
+    }
+}
+
+/// Removes inline formatting tags so titles and abstracts deserialize as text
+fn strip_inline_tags(xml: &str) -> String {
+    let mut xml = xml.to_string();
+    for tag in INLINE_TAGS {
+        xml = xml
+            .replace(&format!("<{}>", tag), "")
+            .replace(&format!("</{}>", tag), "");
+    }
+    xml
+}
+
+#[async_trait]
+impl Collector for PubMedCollector {
+    fn name(&self) -> &str {
+        "PubMed"
+    }
+
+    fn description(&self) -> &str {
+        "Collects biomedical literature from PubMed via NCBI E-utilities"
+    }
+
+    fn default_query(&self) -> &str {
+        "artificial intelligence"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let retmax = max_results.to_string();
+        let search_xml = self
+            .get(
+                "esearch.fcgi",
+                &[("db", "pubmed"), ("term", query), ("retmax", &retmax)],
+            )
+            .await?;
+
+        let search: ESearchResult = from_str(&search_xml).map_err(|e| {
+            tracing::error!("Failed to parse PubMed esearch XML: {}", e);
+            format!("Failed to parse PubMed esearch response: {}", e)
+        })?;
+
+        if search.id_list.ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let ids = search.id_list.ids.join(",");
+        let fetch_xml = self
+            .get(
+                "efetch.fcgi",
+                &[("db", "pubmed"), ("id", &ids), ("retmode", "xml")],
+            )
+            .await?;
+
+        let set: PubmedArticleSet = from_str(&strip_inline_tags(&fetch_xml)).map_err(|e| {
+            tracing::error!("Failed to parse PubMed efetch XML: {}", e);
+            format!("Failed to parse PubMed efetch response: {}", e)
+        })?;
+
+        Ok(set
+            .articles
+            .into_iter()
+            .take(max_results)
+            .map(PubmedArticle::into_article)
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use wiremock::matchers::{method, path};
+    use wiremock::{Mock, MockServer, ResponseTemplate};
+
+    #[tokio::test(start_paused = true)]
+    async fn requests_are_spaced_by_the_rate_limit() {
+        let server = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/esearch.fcgi"))
+            .respond_with(ResponseTemplate::new(200).set_body_string("<eSearchResult/>"))
+            .expect(2)
+            .mount(&server)
+            .await;
+        // The paused clock jumps to the next timer whenever the test waits on
+        // the server, so a request timeout would fire at once
+        let collector = PubMedCollector {
+            client: reqwest::Client::new(),
+            ..PubMedCollector::new().with_base_url(server.uri())
+        };
+
+        let first = Instant::now();
+        collector.get("esearch.fcgi", &[]).await.unwrap();
+        collector.get("esearch.fcgi", &[]).await.unwrap();
+        let second = collector.last_request.lock().await.unwrap();
+
+        assert!(second - first >= MIN_INTERVAL);
+    }
+}
@@ -0,0 +1,342 @@
+use async_trait::async_trait;
+use chrono::DateTime;
+use serde::Deserialize;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://www.reddit.com";
+/// Reddit caps `limit` at 100
+const MAX_LIMIT: usize = 100;
+/// Upper bound on pages fetched when self-posts are filtered out
+const MAX_PAGES: usize = 5;
+
+/// Collects posts from subreddit listings and Reddit search
+///
+/// `r/<sub>` (or `r/rust+MachineLearning`) picks the subreddits, falling
+/// back to the configured defaults. `top`, `new` or `hot` picks the
+/// listing, `t:day|week|month|year|all` the time window for `top` and
+/// search, and `-self` drops text-only posts. Any other words search within
+/// the subreddits instead of listing them.
+///
+/// Reddit rejects generic clients, so a descriptive User-Agent is required.
+pub struct RedditCollector {
+    client: reqwest::Client,
+    base_url: String,
+    user_agent: String,
+    subreddits: Vec<String>,
+    time_window: String,
+}
+
+impl RedditCollector {
+    /// Creates a collector identifying itself with `user_agent`, e.g.
+    /// `linux:xplorer:0.1.0 (by /u/yourname)`
+    pub fn new(user_agent: impl Into<String>) -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            user_agent: user_agent.into(),
+            subreddits: Vec::new(),
+            time_window: "day".to_string(),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the subreddits read when the query names none
+    pub fn with_subreddits(mut self, subreddits: Vec<String>) -> Self {
+        self.subreddits = subreddits;
+        self
+    }
+
+    /// Sets the default `t` time window (`hour`, `day`, `week`, `month`, `year`, `all`)
+    pub fn with_time_window(mut self, time_window: impl Into<String>) -> Self {
+        self.time_window = time_window.into();
+        self
+    }
+
+    async fn fetch_page(&self, url: &str, params: &[(&str, &str)]) -> CollectorResult<Listing> {
+        tracing::info!("Fetching from Reddit: {} ({:?})", url, params);
+
+        let response = self
+            .client
+            .get(url)
+            .header("User-Agent", &self.user_agent)
+            .query(params)
+            .send()
+            .await?
+            .error_for_status()?;
+        let listing: ListingResponse = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse Reddit response: {}", e);
+            format!("Failed to parse Reddit response: {}", e)
+        })?;
+
+        Ok(listing.data)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct ListingResponse {
+    data: Listing,
+}
+
+#[derive(Debug, Deserialize)]
+struct Listing {
+    after: Option<String>,
+    #[serde(default)]
+    children: Vec<Child>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Child {
+    data: Post,
+}
+
+#[derive(Debug, Deserialize)]
+struct Post {
+    title: String,
+    author: Option<String>,
+    permalink: String,
+    url: Option<String>,
+    #[serde(default)]
+    selftext: String,
+    subreddit: String,
+    score: Option<i64>,
+    num_comments: Option<u64>,
+    created_utc: Option<f64>,
+    #[serde(default)]
+    is_self: bool,
+    link_flair_text: Option<String>,
+}
+
+impl Post {
+    fn into_article(self) -> Article {
+        let discussion_url = format!("https://www.reddit.com{}", self.permalink);
+
+        let mut article = Article {
+            title: self.title,
+            authors: self.author.into_iter().collect(),
+            url: self
+                .url
+                .filter(|url| !self.is_self && !url.is_empty())
+                .unwrap_or_else(|| discussion_url.clone()),
+            published_date: self
+                .created_utc
+                .and_then(|secs| DateTime::from_timestamp(secs as i64, 0))
+                .map(|date| date.to_rfc3339())
+                .unwrap_or_default(),
+            summary: self
+                .selftext
+                .split_whitespace()
+                .collect::<Vec<_>>()
+                .join(" "),
+            source: "Reddit".to_string(),
+            ..Default::default()
+        };
+
+        article
+            .metadata
+            .insert("subreddit".to_string(), format!("r/{}", self.subreddit));
+        if let Some(score) = self.score {
+            article
+                .metadata
+                .insert("score".to_string(), score.to_string());
+        }
+        if let Some(comments) = self.num_comments {
+            article
+                .metadata
+                .insert("comments".to_string(), comments.to_string());
+        }
+        if let Some(flair) = self.link_flair_text.filter(|f| !f.is_empty()) {
+            article.metadata.insert("flair".to_string(), flair);
+        }
+        article
+            .metadata
+            .insert("discussion".to_string(), discussion_url);
+
+        article
+    }
+}
+
+/// Subreddits, listing, time window, filters and search words parsed from the query
+struct RedditQuery {
+    subreddits: Vec<String>,
+    listing: Option<String>,
+    time_window: Option<String>,
+    exclude_self: bool,
+    words: Vec<String>,
+}
+
+impl RedditQuery {
+    fn parse(query: &str) -> Self {
+        let mut parsed = Self {
+            subreddits: Vec::new(),
+            listing: None,
+            time_window: None,
+            exclude_self: false,
+            words: Vec::new(),
+        };
+
+        for word in query.split_whitespace() {
+            if let Some(sub) = word.strip_prefix("/r/").or_else(|| word.strip_prefix("r/")) {
+                parsed
+                    .subreddits
+                    .push(sub.trim_end_matches('/').to_string());
+            } else if let Some(window) = word.strip_prefix("t:") {
+                parsed.time_window = Some(window.to_string());
+            } else if word == "-self" {
+                parsed.exclude_self = true;
+            } else if matches!(word, "top" | "new" | "hot") {
+                parsed.listing = Some(word.to_string());
+            } else {
+                parsed.words.push(word.to_string());
+            }
+        }
+
+        parsed
+    }
+}
+
+#[async_trait]
+impl Collector for RedditCollector {
+    fn name(&self) -> &str {
+        "Reddit"
+    }
+
+    fn description(&self) -> &str {
+        "Collects top, new or matching posts from subreddits with score and comments"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let query = RedditQuery::parse(query);
+        let subreddits = if query.subreddits.is_empty() {
+            self.subreddits.join("+")
+        } else {
+            query.subreddits.join("+")
+        };
+        let time_window = query.time_window.as_deref().unwrap_or(&self.time_window);
+        let listing = query.listing.as_deref().unwrap_or("top");
+        let search = query.words.join(" ");
+
+        let url = match (subreddits.is_empty(), search.is_empty()) {
+            (true, true) => format!("{}/r/all/{}.json", self.base_url, listing),
+            (true, false) => format!("{}/search.json", self.base_url),
+            (false, true) => format!("{}/r/{}/{}.json", self.base_url, subreddits, listing),
+            (false, false) => format!("{}/r/{}/search.json", self.base_url, subreddits),
+        };
+        let limit = max_results.clamp(1, MAX_LIMIT).to_string();
+        let mut params = vec![
+            ("limit", limit.as_str()),
+            ("t", time_window),
+            // Otherwise `&`, `<` and `>` come back HTML-escaped
+            ("raw_json", "1"),
+        ];
+        if !search.is_empty() {
+            params.extend([("q", search.as_str()), ("sort", listing)]);
+            if !subreddits.is_empty() {
+                params.push(("restrict_sr", "1"));
+            }
+        }
+
+        let mut articles = Vec::new();
+        let mut after = None;
+        for _ in 0..MAX_PAGES {
+            let mut page_params = params.clone();
+            if let Some(after) = after.as_deref() {
+                page_params.push(("after", after));
+            }
+            let page = self.fetch_page(&url, &page_params).await?;
+
+            articles.extend(
+                page.children
+                    .into_iter()
+                    .map(|child| child.data)
+                    .filter(|post| !(query.exclude_self && post.is_self))
+                    .map(Post::into_article),
+            );
+
+            match page.after {
+                Some(next) if articles.len() < max_results => after = Some(next),
+                _ => break,
+            }
+        }
+
+        articles.truncate(max_results);
+        Ok(articles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn post(value: serde_json::Value) -> Post {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn query_words_are_split_into_filters() {
+        let query = RedditQuery::parse("r/rust /r/programming/ new t:month -self async traits");
+        assert_eq!(query.subreddits, vec!["rust", "programming"]);
+        assert_eq!(query.listing.as_deref(), Some("new"));
+        assert_eq!(query.time_window.as_deref(), Some("month"));
+        assert!(query.exclude_self);
+        assert_eq!(query.words, vec!["async", "traits"]);
+
+        let query = RedditQuery::parse("");
+        assert!(query.subreddits.is_empty());
+        assert!(query.listing.is_none());
+        assert!(!query.exclude_self);
+    }
+
+    #[test]
+    fn link_post_becomes_article() {
+        let article = post(serde_json::json!({
+            "title": "Rust 2.0",
+            "author": "someone",
+            "permalink": "/r/rust/comments/a/post/",
+            "url": "https://example.com/a",
+            "subreddit": "rust",
+            "score": 42,
+            "num_comments": 7,
+            "created_utc": 1717200000.0,
+            "link_flair_text": "news"
+        }))
+        .into_article();
+
+        assert_eq!(article.url, "https://example.com/a");
+        assert_eq!(article.authors, vec!["someone"]);
+        assert_eq!(article.published_date, "2024-06-01T00:00:00+00:00");
+        assert_eq!(article.metadata["subreddit"], "r/rust");
+        assert_eq!(article.metadata["score"], "42");
+        assert_eq!(article.metadata["comments"], "7");
+        assert_eq!(article.metadata["flair"], "news");
+        assert_eq!(
+            article.metadata["discussion"],
+            "https://www.reddit.com/r/rust/comments/a/post/"
+        );
+    }
+
+    #[test]
+    fn self_post_links_to_its_discussion() {
+        let article = post(serde_json::json!({
+            "title": "Question",
+            "permalink": "/r/rust/comments/b/post/",
+            "url": "https://www.reddit.com/r/rust/comments/b/post/",
+            "selftext": "Some  text\n\nmore",
+            "subreddit": "rust",
+            "is_self": true
+        }))
+        .into_article();
+
+        assert_eq!(
+            article.url,
+            "https://www.reddit.com/r/rust/comments/b/post/"
+        );
+        assert_eq!(article.summary, "Some text more");
+        assert!(article.authors.is_empty());
+        assert!(!article.metadata.contains_key("flair"));
+    }
+}
@@ -0,0 +1,440 @@
+use async_trait::async_trait;
+use reqwest::StatusCode;
+use scraper::{ElementRef, Html, Selector};
+use serde::Deserialize;
+use url::Url;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const USER_AGENT: &str = concat!(
+    "xplorer/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/MechanicalGirlDev/xplorer)"
+);
+/// Product token matched against `User-agent` lines in robots.txt
+const ROBOTS_TOKEN: &str = "xplorer";
+
+/// Describes one listing page and where its items live, as read from
+/// `SCRAPERS_FILE`
+///
+/// Only `name`, `url` and `item` are required. Without a `title` selector the
+/// whole item's text is the title; without a `link` selector the item itself
+/// (if it is a link) or its first link is used, falling back to the page URL.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScrapeConfig {
+    pub name: String,
+    pub url: String,
+    pub description: Option<String>,
+    /// Selects each entry on the page, e.g. `article.news-item`
+    pub item: String,
+    pub title: Option<String>,
+    pub link: Option<String>,
+    /// Uses the `datetime` attribute when present (as on `<time>`)
+    pub date: Option<String>,
+    pub summary: Option<String>,
+}
+
+/// Collects articles from an HTML listing page using CSS selectors
+///
+/// Meant for sites with neither an API nor a feed, such as lab news pages or
+/// conference "accepted papers" pages. Relative links are resolved against
+/// the page URL, and the page is only fetched when the site's robots.txt
+/// allows it. The query is a keyword filter over titles and summaries.
+pub struct ScrapeCollector {
+    client: reqwest::Client,
+    name: String,
+    description: String,
+    url: Url,
+    item: Selector,
+    title: Option<Selector>,
+    link: Option<Selector>,
+    date: Option<Selector>,
+    summary: Option<Selector>,
+}
+
+impl ScrapeCollector {
+    /// Fails if the URL or any selector does not parse
+    pub fn new(config: ScrapeConfig) -> CollectorResult<Self> {
+        let url = Url::parse(&config.url)
+            .map_err(|e| format!("Invalid URL for scraper {}: {}", config.name, e))?;
+        let selector = |css: &str| {
+            Selector::parse(css).map_err(|e| {
+                format!(
+                    "Invalid selector {:?} for scraper {}: {}",
+                    css, config.name, e
+                )
+            })
+        };
+        let optional = |css: &Option<String>| css.as_deref().map(selector).transpose();
+
+        Ok(Self {
+            client: http_client(),
+            description: config
+                .description
+                .clone()
+                .unwrap_or_else(|| format!("Scrapes articles from {}", url)),
+            item: selector(&config.item)?,
+            title: optional(&config.title)?,
+            link: optional(&config.link)?,
+            date: optional(&config.date)?,
+            summary: optional(&config.summary)?,
+            name: config.name,
+            url,
+        })
+    }
+
+    /// Fetches the site's robots.txt and checks whether the listing page may be
+    /// crawled
+    async fn allowed_by_robots(&self) -> CollectorResult<bool> {
+        let robots_url = self.url.join("/robots.txt")?;
+
+        tracing::info!("Fetching robots.txt for {}: {}", self.name, robots_url);
+
+        let response = self
+            .client
+            .get(robots_url)
+            .header("User-Agent", USER_AGENT)
+            .send()
+            .await?;
+        let status = response.status();
+        // A missing or forbidden robots.txt places no restrictions, while a
+        // server error means the site's wishes are unknown
+        if status.is_client_error() && status != StatusCode::TOO_MANY_REQUESTS {
+            return Ok(true);
+        }
+        if !status.is_success() {
+            return Err(format!("robots.txt for {} unavailable: {}", self.name, status).into());
+        }
+
+        let robots = response.text().await?;
+        let mut path = self.url.path().to_string();
+        if let Some(query) = self.url.query() {
+            path.push('?');
+            path.push_str(query);
+        }
+        Ok(robots_allows(&robots, ROBOTS_TOKEN, &path))
+    }
+
+    fn parse_page(&self, body: &str) -> Vec<Article> {
+        let document = Html::parse_document(body);
+        let any_link = Selector::parse("a[href]").expect("valid selector");
+
+        document
+            .select(&self.item)
+            .filter_map(|item| {
+                let title = match &self.title {
+                    Some(selector) => item.select(selector).next().map(text)?,
+                    None => text(item),
+                };
+                if title.is_empty() {
+                    return None;
+                }
+
+                let href = match &self.link {
+                    Some(selector) => item
+                        .select(selector)
+                        .find_map(|link| link.value().attr("href")),
+                    None => item.value().attr("href").or_else(|| {
+                        item.select(&any_link)
+                            .find_map(|link| link.value().attr("href"))
+                    }),
+                };
+                let url = href
+                    .and_then(|href| self.url.join(href.trim()).ok())
+                    .unwrap_or_else(|| self.url.clone());
+
+                let date = self.date.as_ref().and_then(|selector| {
+                    let element = item.select(selector).next()?;
+                    Some(
+                        element
+                            .value()
+                            .attr("datetime")
+                            .map(|date| date.trim().to_string())
+                            .unwrap_or_else(|| text(element)),
+                    )
+                });
+                let summary = self
+                    .summary
+                    .as_ref()
+                    .and_then(|selector| item.select(selector).next().map(text));
+
+                Some(Article {
+                    title,
+                    url: url.to_string(),
+                    published_date: date.unwrap_or_default(),
+                    summary: summary.unwrap_or_default(),
+                    source: self.name.clone(),
+                    ..Default::default()
+                })
+            })
+            .collect()
+    }
+}
+
+/// The element's text with whitespace collapsed
+fn text(element: ElementRef) -> String {
+    element
+        .text()
+        .flat_map(str::split_whitespace)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// A robots.txt group: the user agents it names and its `(allow, pattern)` rules
+#[derive(Default)]
+struct RobotsGroup<'a> {
+    agents: Vec<String>,
+    rules: Vec<(bool, &'a str)>,
+}
+
+/// Applies the robots.txt rules (RFC 9309) for `token` to `path`
+///
+/// Rules come from the groups naming `token`, or the `*` group if none do.
+/// The longest matching pattern wins, and `Allow` wins ties.
+fn robots_allows(robots: &str, token: &str, path: &str) -> bool {
+    let mut groups: Vec<RobotsGroup> = Vec::new();
+    let mut reading_agents = false;
+
+    for line in robots.lines() {
+        let line = line.split('#').next().unwrap_or_default();
+        let Some((key, value)) = line.split_once(':') else {
+            continue;
+        };
+        let value = value.trim();
+        match key.trim().to_ascii_lowercase().as_str() {
+            "user-agent" => {
+                if !reading_agents {
+                    groups.push(RobotsGroup::default());
+                }
+                if let Some(group) = groups.last_mut() {
+                    group.agents.push(value.to_ascii_lowercase());
+                }
+                reading_agents = true;
+            }
+            rule @ ("allow" | "disallow") => {
+                reading_agents = false;
+                // An empty `Disallow:` allows everything
+                if let (Some(group), false) = (groups.last_mut(), value.is_empty()) {
+                    group.rules.push((rule == "allow", value));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let names = |group: &RobotsGroup, agent: &str| group.agents.iter().any(|a| a == agent);
+    let agent = if groups.iter().any(|group| names(group, token)) {
+        token
+    } else {
+        "*"
+    };
+    let rules = groups
+        .iter()
+        .filter(|group| names(group, agent))
+        .flat_map(|group| group.rules.iter().copied());
+
+    rules
+        .filter(|(_, pattern)| robots_pattern_matches(pattern, path))
+        .max_by_key(|(allow, pattern)| (pattern.len(), *allow))
+        .is_none_or(|(allow, _)| allow)
+}
+
+/// Matches a robots.txt path pattern, where `*` matches any run of
+/// characters and a trailing `$` anchors the end
+fn robots_pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+    let mut parts = pattern.split('*');
+    let Some(mut rest) = parts.next().and_then(|prefix| path.strip_prefix(prefix)) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return !anchored || rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(i) => rest = &rest[i + part.len()..],
+            None => return false,
+        }
+    }
+    if anchored {
+        rest.ends_with(last)
+    } else {
+        rest.contains(last)
+    }
+}
+
+#[async_trait]
+impl Collector for ScrapeCollector {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        if !self.allowed_by_robots().await? {
+            tracing::warn!("robots.txt disallows scraping {}", self.url);
+            return Err(format!("robots.txt disallows scraping {}", self.url).into());
+        }
+
+        tracing::info!("Scraping {}: {}", self.name, self.url);
+
+        let response = self
+            .client
+            .get(self.url.clone())
+            .header("User-Agent", USER_AGENT)
+            .send()
+            .await?
+            .error_for_status()?;
+        let body = response.text().await?;
+
+        let keywords: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        let mut articles = self.parse_page(&body);
+        articles.retain(|article| {
+            let text = format!("{} {}", article.title, article.summary).to_lowercase();
+            keywords.iter().all(|keyword| text.contains(keyword))
+        });
+        articles.truncate(max_results);
+
+        Ok(articles)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(item: &str) -> ScrapeConfig {
+        ScrapeConfig {
+            name: "Lab News".to_string(),
+            url: "https://lab.example/news/".to_string(),
+            description: None,
+            item: item.to_string(),
+            title: None,
+            link: None,
+            date: None,
+            summary: None,
+        }
+    }
+
+    #[test]
+    fn invalid_url_or_selector_is_rejected() {
+        assert!(ScrapeCollector::new(config("article.news")).is_ok());
+        assert!(ScrapeCollector::new(config("article[")).is_err());
+        assert!(ScrapeCollector::new(ScrapeConfig {
+            summary: Some("p[".to_string()),
+            ..config("article.news")
+        })
+        .is_err());
+        assert!(ScrapeCollector::new(ScrapeConfig {
+            url: "lab.example/news".to_string(),
+            ..config("article.news")
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn selected_fields_become_article() {
+        let collector = ScrapeCollector::new(ScrapeConfig {
+            title: Some("h2".to_string()),
+            link: Some("h2 a".to_string()),
+            date: Some("time".to_string()),
+            summary: Some("p.teaser".to_string()),
+            ..config("article.news")
+        })
+        .unwrap();
+        let articles = collector.parse_page(
+            r#"<article class="news">
+  <h2><a href=" 2024/robot-arm.html ">A new
+    robot arm</a></h2>
+  <time datetime="2024-06-03">June 3</time>
+  <p class="teaser">Our robot &amp; its arm.</p>
+</article>
+<article class="news">
+  <h2><a href="/events/open-day">Open day</a></h2>
+  <time>Soon</time>
+</article>
+<article class="news"><p class="teaser">No title</p></article>"#,
+        );
+
+        assert_eq!(articles.len(), 2);
+        assert_eq!(articles[0].title, "A new robot arm");
+        assert_eq!(
+            articles[0].url,
+            "https://lab.example/news/2024/robot-arm.html"
+        );
+        assert_eq!(articles[0].published_date, "2024-06-03");
+        assert_eq!(articles[0].summary, "Our robot & its arm.");
+        assert_eq!(articles[0].source, "Lab News");
+        assert_eq!(articles[1].url, "https://lab.example/events/open-day");
+        assert_eq!(articles[1].published_date, "Soon");
+        assert_eq!(articles[1].summary, "");
+    }
+
+    #[test]
+    fn item_without_selectors_uses_its_own_text_and_link() {
+        let collector = ScrapeCollector::new(config("li")).unwrap();
+        let articles = collector.parse_page(
+            r#"<ul>
+<li><a href="paper.pdf">Paper</a> (PDF)</li>
+<li>Talk, no slides</li>
+</ul>"#,
+        );
+
+        assert_eq!(articles.len(), 2);
+        assert_eq!(articles[0].title, "Paper (PDF)");
+        assert_eq!(articles[0].url, "https://lab.example/news/paper.pdf");
+        assert_eq!(articles[1].url, "https://lab.example/news/");
+
+        let collector = ScrapeCollector::new(config("a.paper")).unwrap();
+        let articles = collector.parse_page(r#"<a class="paper" href="/p/1">Paper 1</a>"#);
+        assert_eq!(articles[0].url, "https://lab.example/p/1");
+    }
+
+    #[test]
+    fn robots_rules_for_the_token_override_the_wildcard_group() {
+        let robots = "User-agent: *\nAllow: /\n\nUser-agent: Xplorer\nUser-agent: other\nDisallow: /news # no bots\n";
+        assert!(!robots_allows(robots, "xplorer", "/news/"));
+        assert!(robots_allows(robots, "xplorer", "/about"));
+        assert!(robots_allows(robots, "somebot", "/news/"));
+    }
+
+    #[test]
+    fn robots_longest_match_wins_and_allow_wins_ties() {
+        let robots = "User-agent: *\nDisallow: /news/drafts\nAllow: /news/\nDisallow: /news\nDisallow: /page\nAllow: /page\n";
+        assert!(robots_allows(robots, "xplorer", "/news/"));
+        assert!(!robots_allows(robots, "xplorer", "/news/drafts/1"));
+        assert!(!robots_allows(robots, "xplorer", "/newsletter"));
+        assert!(robots_allows(robots, "xplorer", "/page"));
+
+        assert!(robots_allows(
+            "User-agent: *\nDisallow:\n",
+            "xplorer",
+            "/news/"
+        ));
+        assert!(robots_allows("", "xplorer", "/news/"));
+    }
+
+    #[test]
+    fn robots_patterns_support_wildcards_and_anchors() {
+        assert!(robots_pattern_matches("/news", "/news/1"));
+        assert!(!robots_pattern_matches("/news", "/about/news"));
+        assert!(robots_pattern_matches("/*.pdf", "/papers/1.pdf?download=1"));
+        assert!(robots_pattern_matches("/*.pdf$", "/papers/1.pdf"));
+        assert!(!robots_pattern_matches(
+            "/*.pdf$",
+            "/papers/1.pdf?download=1"
+        ));
+        assert!(robots_pattern_matches("/a*b*c", "/a-x-b-y-c"));
+        assert!(!robots_pattern_matches("/a*b*c", "/a-x-c-y-b"));
+        assert!(robots_pattern_matches("/news$", "/news"));
+        assert!(!robots_pattern_matches("/news$", "/news/"));
+    }
+}
@@ -0,0 +1,150 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://api.semanticscholar.org";
+const SEARCH_FIELDS: &str = "title,authors,abstract,venue,year,url,publicationDate";
+/// The paper search endpoint rejects limits above 100
+const MAX_LIMIT: usize = 100;
+
+/// Collects papers from the Semantic Scholar Graph API paper search
+pub struct SemanticScholarCollector {
+    client: reqwest::Client,
+    base_url: String,
+    api_key: Option<String>,
+}
+
+impl SemanticScholarCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            api_key: None,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the API key sent in the `x-api-key` header for higher rate limits
+    pub fn with_api_key(mut self, api_key: impl Into<String>) -> Self {
+        self.api_key = Some(api_key.into());
+        self
+    }
+}
+
+impl Default for SemanticScholarCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    #[serde(default)]
+    data: Vec<Paper>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Paper {
+    paper_id: String,
+    title: Option<String>,
+    #[serde(default)]
+    authors: Vec<PaperAuthor>,
+    #[serde(rename = "abstract")]
+    abstract_text: Option<String>,
+    venue: Option<String>,
+    year: Option<i32>,
+    url: Option<String>,
+    publication_date: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PaperAuthor {
+    name: Option<String>,
+}
+
+impl Paper {
+    fn into_article(self) -> Article {
+        let url = self
+            .url
+            .unwrap_or_else(|| format!("https://www.semanticscholar.org/paper/{}", self.paper_id));
+        let published_date = self
+            .publication_date
+            .or_else(|| self.year.map(|y| y.to_string()))
+            .unwrap_or_default();
+
+        let mut article = Article {
+            title: self.title.unwrap_or_default().trim().replace('\n', " "),
+            authors: self.authors.into_iter().filter_map(|a| a.name).collect(),
+            url,
+            published_date,
+            summary: self
+                .abstract_text
+                .unwrap_or_default()
+                .trim()
+                .replace('\n', " "),
+            source: "Semantic Scholar".to_string(),
+            ..Default::default()
+        };
+
+        if let Some(venue) = self.venue.filter(|v| !v.is_empty()) {
+            article.metadata.insert("venue".to_string(), venue);
+        }
+        if let Some(year) = self.year {
+            article
+                .metadata
+                .insert("year".to_string(), year.to_string());
+        }
+
+        article
+    }
+}
+
+#[async_trait]
+impl Collector for SemanticScholarCollector {
+    fn name(&self) -> &str {
+        "Semantic Scholar"
+    }
+
+    fn description(&self) -> &str {
+        "Collects papers from Semantic Scholar, including venues not on arXiv"
+    }
+
+    fn default_query(&self) -> &str {
+        "artificial intelligence"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let url = format!("{}/graph/v1/paper/search", self.base_url);
+        let limit = max_results.min(MAX_LIMIT).to_string();
+
+        tracing::info!("Fetching from Semantic Scholar: {} (query: {})", url, query);
+
+        let mut request = self.client.get(&url).query(&[
+            ("query", query),
+            ("limit", limit.as_str()),
+            ("fields", SEARCH_FIELDS),
+        ]);
+        if let Some(api_key) = &self.api_key {
+            request = request.header("x-api-key", api_key);
+        }
+
+        let response = request.send().await?.error_for_status()?;
+        let search: SearchResponse = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse Semantic Scholar response: {}", e);
+            format!("Failed to parse Semantic Scholar response: {}", e)
+        })?;
+
+        Ok(search
+            .data
+            .into_iter()
+            .take(max_results)
+            .map(Paper::into_article)
+            .collect())
+    }
+}
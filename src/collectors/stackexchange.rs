@@ -0,0 +1,237 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+use super::html::decode_entities;
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://api.stackexchange.com/2.3";
+/// The API caps `pagesize` at 100
+const MAX_PAGE_SIZE: usize = 100;
+
+/// Collects questions from a Stack Exchange site via the Stack Exchange API
+///
+/// Any words are searched in question titles and bodies. `tag:<name>`
+/// (repeatable, all must match), `site:<name>` (`stats`, `cs`, ...),
+/// `days:<n>` (only questions asked in the last n days) and
+/// `sort:votes|activity|creation|relevance` refine the search. Responses are
+/// gzip-compressed, and the API's `backoff` requests are honored before the
+/// next call.
+pub struct StackExchangeCollector {
+    client: reqwest::Client,
+    base_url: String,
+    site: String,
+    key: Option<String>,
+    backoff_until: Mutex<Option<Instant>>,
+}
+
+impl StackExchangeCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            site: "stackoverflow".to_string(),
+            key: None,
+            backoff_until: Mutex::new(None),
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the site searched when the query names none (default: stackoverflow)
+    pub fn with_site(mut self, site: impl Into<String>) -> Self {
+        self.site = site.into();
+        self
+    }
+
+    /// Sets the app key, which raises the daily request quota
+    pub fn with_key(mut self, key: impl Into<String>) -> Self {
+        self.key = Some(key.into());
+        self
+    }
+}
+
+impl Default for StackExchangeCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    #[serde(default)]
+    items: Vec<Question>,
+    /// Seconds to wait before calling the same method again
+    backoff: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    error_name: String,
+    error_message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct Question {
+    /// HTML-escaped
+    title: String,
+    link: String,
+    #[serde(default)]
+    tags: Vec<String>,
+    owner: Option<Owner>,
+    score: i64,
+    answer_count: u64,
+    #[serde(default)]
+    is_answered: bool,
+    accepted_answer_id: Option<u64>,
+    creation_date: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct Owner {
+    display_name: Option<String>,
+}
+
+impl Question {
+    fn into_article(self) -> Article {
+        let status = if self.accepted_answer_id.is_some() {
+            "accepted answer"
+        } else if self.is_answered {
+            "answered"
+        } else {
+            "unanswered"
+        };
+
+        let mut article = Article {
+            title: decode_entities(&self.title),
+            authors: self
+                .owner
+                .and_then(|owner| owner.display_name)
+                .map(|name| decode_entities(&name))
+                .into_iter()
+                .collect(),
+            url: self.link,
+            published_date: DateTime::from_timestamp(self.creation_date, 0)
+                .map(|date| date.to_rfc3339())
+                .unwrap_or_default(),
+            source: "Stack Exchange".to_string(),
+            ..Default::default()
+        };
+
+        article
+            .metadata
+            .insert("score".to_string(), self.score.to_string());
+        article
+            .metadata
+            .insert("answers".to_string(), self.answer_count.to_string());
+        article
+            .metadata
+            .insert("status".to_string(), status.to_string());
+        if !self.tags.is_empty() {
+            article
+                .metadata
+                .insert("tags".to_string(), self.tags.join(", "));
+        }
+
+        article
+    }
+}
+
+#[async_trait]
+impl Collector for StackExchangeCollector {
+    fn name(&self) -> &str {
+        "Stack Exchange"
+    }
+
+    fn description(&self) -> &str {
+        "Searches Stack Exchange questions by tag and keyword, with score and answer status"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let mut site = self.site.clone();
+        let mut tags = Vec::new();
+        let mut from_date = None;
+        let mut sort = "votes".to_string();
+        let mut words = Vec::new();
+        for word in query.split_whitespace() {
+            if let Some(tag) = word.strip_prefix("tag:") {
+                tags.push(tag);
+            } else if let Some(name) = word.strip_prefix("site:") {
+                site = name.to_string();
+            } else if let Some(days) = word.strip_prefix("days:") {
+                let since = days
+                    .parse::<u32>()
+                    .ok()
+                    .and_then(|days| chrono::Duration::try_days(days.into()))
+                    .and_then(|days| Utc::now().checked_sub_signed(days))
+                    .ok_or_else(|| format!("Invalid Stack Exchange day count: {}", days))?;
+                from_date = Some(since.timestamp());
+            } else if let Some(order) = word.strip_prefix("sort:") {
+                sort = order.to_string();
+            } else {
+                words.push(word);
+            }
+        }
+
+        let url = format!("{}/search/advanced", self.base_url);
+        let mut params = vec![
+            ("site", site),
+            ("sort", sort),
+            ("order", "desc".to_string()),
+            ("pagesize", max_results.clamp(1, MAX_PAGE_SIZE).to_string()),
+        ];
+        if !words.is_empty() {
+            params.push(("q", words.join(" ")));
+        }
+        if !tags.is_empty() {
+            params.push(("tagged", tags.join(";")));
+        }
+        if let Some(from_date) = from_date {
+            params.push(("fromdate", from_date.to_string()));
+        }
+        if let Some(key) = &self.key {
+            params.push(("key", key.clone()));
+        }
+
+        // The backoff applies to every caller, so it is held across the request
+        let mut backoff_until = self.backoff_until.lock().await;
+        if let Some(until) = backoff_until.take() {
+            tracing::info!("Honoring Stack Exchange backoff");
+            tokio::time::sleep_until(until).await;
+        }
+
+        tracing::info!("Fetching from Stack Exchange: {} ({:?})", url, params);
+
+        let response = self.client.get(&url).query(&params).send().await?;
+        if !response.status().is_success() {
+            let status = response.status();
+            let message = match response.json::<ErrorResponse>().await {
+                Ok(error) => format!("{}: {}", error.error_name, error.error_message),
+                Err(_) => status.to_string(),
+            };
+            return Err(format!("Stack Exchange API error: {}", message).into());
+        }
+        let search: SearchResponse = response.json().await.map_err(|e| {
+            tracing::error!("Failed to parse Stack Exchange response: {}", e);
+            format!("Failed to parse Stack Exchange response: {}", e)
+        })?;
+
+        if let Some(backoff) = search.backoff {
+            tracing::warn!("Stack Exchange asked to back off for {} seconds", backoff);
+            *backoff_until = Some(Instant::now() + Duration::from_secs(backoff));
+        }
+
+        Ok(search
+            .items
+            .into_iter()
+            .take(max_results)
+            .map(Question::into_article)
+            .collect())
+    }
+}
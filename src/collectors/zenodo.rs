@@ -0,0 +1,213 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+
+use super::html::to_plain_text;
+use super::{http_client, Article, Collector, CollectorResult};
+
+const DEFAULT_BASE_URL: &str = "https://zenodo.org/api";
+/// Zenodo caps `size` at 25 for anonymous requests
+const MAX_PAGE_SIZE: usize = 25;
+const MAX_PAGES: usize = 10;
+
+/// Collects datasets, software and publications from the Zenodo records API
+///
+/// The query uses Zenodo search syntax (`title:"llama" AND keywords:nlp`).
+/// `type:<resource type>` (`dataset`, `software`, `publication`, ...) and
+/// `community:<id>` narrow the results, which are sorted newest first
+/// unless `sort:bestmatch` is given.
+pub struct ZenodoCollector {
+    client: reqwest::Client,
+    base_url: String,
+    access_token: Option<String>,
+}
+
+impl ZenodoCollector {
+    pub fn new() -> Self {
+        Self {
+            client: http_client(),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            access_token: None,
+        }
+    }
+
+    pub fn with_base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Sets the personal access token sent as a bearer token
+    pub fn with_access_token(mut self, access_token: impl Into<String>) -> Self {
+        self.access_token = Some(access_token.into());
+        self
+    }
+}
+
+impl Default for ZenodoCollector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct SearchResponse {
+    hits: Hits,
+}
+
+#[derive(Debug, Deserialize)]
+struct Hits {
+    #[serde(default)]
+    hits: Vec<Record>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Record {
+    id: u64,
+    doi: Option<String>,
+    links: Option<Links>,
+    metadata: RecordMetadata,
+}
+
+#[derive(Debug, Deserialize)]
+struct Links {
+    self_html: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecordMetadata {
+    title: String,
+    doi: Option<String>,
+    publication_date: Option<String>,
+    description: Option<String>,
+    #[serde(default)]
+    creators: Vec<Creator>,
+    resource_type: Option<ResourceType>,
+    version: Option<String>,
+    #[serde(default)]
+    keywords: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Creator {
+    name: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResourceType {
+    title: Option<String>,
+    #[serde(rename = "type")]
+    resource_type: Option<String>,
+}
+
+impl Record {
+    fn into_article(self) -> Article {
+        let metadata = self.metadata;
+        let url = self
+            .links
+            .and_then(|links| links.self_html)
+            .unwrap_or_else(|| format!("https://zenodo.org/records/{}", self.id));
+
+        let mut article = Article {
+            title: metadata.title,
+            // Creators are "Family, Given"
+            authors: metadata
+                .creators
+                .into_iter()
+                .map(|creator| match creator.name.split_once(", ") {
+                    Some((family, given)) => format!("{} {}", given, family),
+                    None => creator.name,
+                })
+                .collect(),
+            url,
+            published_date: metadata.publication_date.unwrap_or_default(),
+            summary: to_plain_text(&metadata.description.unwrap_or_default()),
+            source: "Zenodo".to_string(),
+            ..Default::default()
+        };
+
+        let resource_type = metadata
+            .resource_type
+            .and_then(|t| t.title.or(t.resource_type));
+        if let Some(resource_type) = resource_type {
+            article.metadata.insert("type".to_string(), resource_type);
+        }
+        if let Some(doi) = self.doi.or(metadata.doi) {
+            article.metadata.insert("doi".to_string(), doi);
+        }
+        if let Some(version) = metadata.version {
+            article.metadata.insert("version".to_string(), version);
+        }
+        if !metadata.keywords.is_empty() {
+            article
+                .metadata
+                .insert("keywords".to_string(), metadata.keywords.join(", "));
+        }
+
+        article
+    }
+}
+
+#[async_trait]
+impl Collector for ZenodoCollector {
+    fn name(&self) -> &str {
+        "Zenodo"
+    }
+
+    fn description(&self) -> &str {
+        "Collects datasets, software and papers from Zenodo, labelled by resource type"
+    }
+
+    async fn collect(&self, query: &str, max_results: usize) -> CollectorResult<Vec<Article>> {
+        let mut params = vec![("sort", "mostrecent".to_string())];
+        let mut terms = Vec::new();
+        for word in query.split_whitespace() {
+            if let Some(resource_type) = word.strip_prefix("type:") {
+                params.push(("type", resource_type.to_string()));
+            } else if let Some(community) = word.strip_prefix("community:") {
+                params.push(("communities", community.to_string()));
+            } else if let Some(sort) = word.strip_prefix("sort:") {
+                params[0].1 = sort.to_string();
+            } else {
+                terms.push(word);
+            }
+        }
+        params.push(("q", terms.join(" ")));
+
+        let url = format!("{}/records", self.base_url);
+        let size = max_results.clamp(1, MAX_PAGE_SIZE);
+        let mut articles = Vec::new();
+
+        for page in 1..=MAX_PAGES {
+            tracing::info!(
+                "Fetching from Zenodo: {} ({:?}, page {})",
+                url,
+                params,
+                page
+            );
+
+            let mut request = self
+                .client
+                .get(&url)
+                .query(&params)
+                .query(&[("size", size), ("page", page)]);
+            if let Some(token) = &self.access_token {
+                request = request.bearer_auth(token);
+            }
+
+            let response = request.send().await?.error_for_status()?;
+            let search: SearchResponse = response.json().await.map_err(|e| {
+                tracing::error!("Failed to parse Zenodo response: {}", e);
+                format!("Failed to parse Zenodo response: {}", e)
+            })?;
+
+            let last_page = search.hits.hits.len() < size;
+            articles.extend(search.hits.hits.into_iter().map(Record::into_article));
+
+            if last_page || articles.len() >= max_results {
+                break;
+            }
+        }
+
+        articles.truncate(max_results);
+        Ok(articles)
+    }
+}
@@ -9,11 +9,10 @@ pub fn collect_command() -> CreateCommand {
             CreateCommandOption::new(
                 CommandOptionType::String,
                 "source",
-                "Source to collect from (arxiv, all)",
+                "Source to collect from (see /sources, or all)",
             )
             .required(true)
-            .add_string_choice("Arxiv", "arxiv")
-            .add_string_choice("All Sources", "all"),
+            .set_autocomplete(true),
         )
         .add_option(
             CreateCommandOption::new(CommandOptionType::String, "query", "Search query")
@@ -1,12 +1,11 @@
 mod bot;
-mod collectors;
 mod commands;
 
-use bot::Bot;
+use bot::{env_var, Bot};
 use serenity::all::GatewayIntents;
 use serenity::Client;
-use std::env;
 use tokio_cron_scheduler::{Job, JobScheduler};
+use xplorer::collectors;
 
 #[tokio::main]
 async fn main() {
@@ -19,15 +18,14 @@ async fn main() {
     dotenv::dotenv().ok();
 
     // Get Discord token
-    let token = env::var("DISCORD_TOKEN").expect("DISCORD_TOKEN must be set in environment");
+    let token = env_var("DISCORD_TOKEN").expect("DISCORD_TOKEN must be set in environment");
 
     // Get configuration
-    let default_query = env::var("ARXIV_SEARCH_QUERY").unwrap_or_else(|_| "cat:cs.AI".to_string());
-    let default_max_results = env::var("ARXIV_MAX_RESULTS")
-        .unwrap_or_else(|_| "10".to_string())
-        .parse::<usize>()
+    let default_query = env_var("ARXIV_SEARCH_QUERY").unwrap_or_else(|| "cat:cs.AI".to_string());
+    let default_max_results = env_var("ARXIV_MAX_RESULTS")
+        .and_then(|s| s.parse::<usize>().ok())
         .unwrap_or(10);
-    let schedule = env::var("COLLECTION_SCHEDULE").unwrap_or_else(|_| "0 0 9 * * *".to_string());
+    let schedule = env_var("COLLECTION_SCHEDULE").unwrap_or_else(|| "0 0 9 * * *".to_string());
 
     tracing::info!("Starting xplorer Discord bot");
     tracing::info!("Default query: {}", default_query);
@@ -53,9 +51,7 @@ async fn main() {
 
         if let Ok(scheduler) = scheduler {
             // Get channel ID from environment if set
-            let channel_id = env::var("CHANNEL_ID")
-                .ok()
-                .and_then(|s| s.parse::<u64>().ok());
+            let channel_id = env_var("CHANNEL_ID").and_then(|s| s.parse::<u64>().ok());
 
             if let Some(channel_id) = channel_id {
                 tracing::info!("Periodic collection will post to channel {}", channel_id);
@@ -1,4 +1,14 @@
-use xplorer::collectors::{Article, ArxivCollector, Collector, ExampleArticleCollector};
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+use xplorer::collectors::{
+    AclAnthologyCollector, AdsCollector, Article, ArxivCollector, BioRxivCollector,
+    BlueskyCollector, Collector, CoreCollector, CrossrefCollector, DblpCollector, DevToCollector,
+    EuropePmcCollector, ExampleArticleCollector, FeedCollector, GitHubCollector,
+    HackerNewsCollector, HuggingFacePapersCollector, IetfCollector, InspireHepCollector,
+    LobstersCollector, MastodonCollector, OpenAlexCollector, OpenReviewCollector, PubMedCollector,
+    RedditCollector, ScrapeCollector, ScrapeConfig, SemanticScholarCollector,
+    StackExchangeCollector, ZenodoCollector,
+};
 
 #[tokio::test]
 async fn test_arxiv_collector_creation() {
@@ -7,6 +17,23 @@ async fn test_arxiv_collector_creation() {
     assert!(!collector.description().is_empty());
 }
 
+#[test]
+fn test_default_queries() {
+    let arxiv = ArxivCollector::new().with_default_query("cat:cs.LG");
+    assert_eq!(arxiv.default_query(), "cat:cs.LG");
+    assert_eq!(ArxivCollector::new().default_query(), "cat:cs.AI");
+
+    let feed = FeedCollector::new("Lab Blog", vec![]);
+    assert_eq!(feed.default_query(), "");
+    assert_eq!(HuggingFacePapersCollector::new().default_query(), "");
+
+    // Search-only sources need something to search for
+    assert_eq!(
+        SemanticScholarCollector::new().default_query(),
+        "artificial intelligence"
+    );
+}
+
 #[tokio::test]
 async fn test_article_serialization() {
     let article = Article {
@@ -16,6 +43,7 @@ async fn test_article_serialization() {
         published_date: "2024-01-01".to_string(),
         summary: "This is a test summary".to_string(),
         source: "Arxiv".to_string(),
+        ..Default::default()
     };
 
     let json = serde_json::to_string(&article).unwrap();
@@ -47,3 +75,1760 @@ async fn test_example_collector_creation() {
     let articles = result.unwrap();
     assert!(articles.is_empty());
 }
+
+#[tokio::test]
+async fn test_semantic_scholar_collector() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/graph/v1/paper/search"))
+        .and(query_param("query", "graph neural networks"))
+        .and(query_param("limit", "2"))
+        .and(header("x-api-key", "test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "total": 1,
+            "offset": 0,
+            "data": [{
+                "paperId": "abc123",
+                "title": "A Survey of\nGraph Networks",
+                "authors": [{"authorId": "1", "name": "Ada Lovelace"}],
+                "abstract": "We survey graph networks.",
+                "venue": "NeurIPS",
+                "year": 2023,
+                "url": "https://www.semanticscholar.org/paper/abc123",
+                "publicationDate": null
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = SemanticScholarCollector::new()
+        .with_base_url(server.uri())
+        .with_api_key("test-key");
+    assert_eq!(collector.name(), "Semantic Scholar");
+
+    let articles = collector.collect("graph neural networks", 2).await.unwrap();
+    assert_eq!(articles.len(), 1);
+    let article = &articles[0];
+    assert_eq!(article.title, "A Survey of Graph Networks");
+    assert_eq!(article.authors, vec!["Ada Lovelace"]);
+    assert_eq!(article.url, "https://www.semanticscholar.org/paper/abc123");
+    assert_eq!(article.published_date, "2023");
+    assert_eq!(article.summary, "We survey graph networks.");
+    assert_eq!(article.metadata["venue"], "NeurIPS");
+    assert_eq!(article.metadata["year"], "2023");
+}
+
+#[tokio::test]
+async fn test_openalex_collector() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("search", "protein folding"))
+        .and(query_param(
+            "filter",
+            "concepts.id:C41008148,from_publication_date:2024-01-01",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "meta": {"count": 1},
+            "results": [{
+                "id": "https://openalex.org/W1",
+                "doi": "https://doi.org/10.1234/abc",
+                "display_name": "Folding Proteins",
+                "publication_date": "2024-03-01",
+                "publication_year": 2024,
+                "type": "article",
+                "cited_by_count": 7,
+                "authorships": [{"author": {"display_name": "Grace Hopper"}}],
+                "primary_location": {"source": {"display_name": "Nature"}},
+                "abstract_inverted_index": {"We": [0], "fold": [1, 3], "and": [2]}
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = OpenAlexCollector::new().with_base_url(server.uri());
+    let articles = collector
+        .collect(
+            "protein concepts.id:C41008148 folding from_publication_date:2024-01-01",
+            5,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(articles.len(), 1);
+    let article = &articles[0];
+    assert_eq!(article.title, "Folding Proteins");
+    assert_eq!(article.url, "https://doi.org/10.1234/abc");
+    assert_eq!(article.summary, "We fold and fold");
+    assert_eq!(article.authors, vec!["Grace Hopper"]);
+    assert_eq!(article.metadata["venue"], "Nature");
+    assert_eq!(article.metadata["citations"], "7");
+}
+
+#[tokio::test]
+async fn test_crossref_collector_search_strips_jats() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works"))
+        .and(query_param("query.bibliographic", "deep learning"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": "ok",
+            "message": {
+                "items": [{
+                    "DOI": "10.1109/5.771073",
+                    "title": ["Deep Learning"],
+                    "author": [{"given": "Yann", "family": "LeCun"}],
+                    "abstract": "<jats:title>Abstract</jats:title><jats:p>Deep learning &amp; <jats:italic>more</jats:italic>.</jats:p><jats:p>&#x3B1; too</jats:p>",
+                    "container-title": ["Proceedings of the IEEE"],
+                    "issued": {"date-parts": [[2015, 5]]},
+                    "type": "journal-article"
+                }]
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = CrossrefCollector::new().with_base_url(server.uri());
+    let articles = collector.collect("deep learning", 5).await.unwrap();
+
+    assert_eq!(articles.len(), 1);
+    let article = &articles[0];
+    assert_eq!(article.summary, "Deep learning & more. α too");
+    assert_eq!(article.authors, vec!["Yann LeCun"]);
+    assert_eq!(article.url, "https://doi.org/10.1109/5.771073");
+    assert_eq!(article.published_date, "2015-05");
+    assert_eq!(article.metadata["venue"], "Proceedings of the IEEE");
+}
+
+#[tokio::test]
+async fn test_crossref_collector_resolves_doi() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/works/10.1145/3292500.3330701"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": "ok",
+            "message": {
+                "DOI": "10.1145/3292500.3330701",
+                "title": ["Optuna"],
+                "published": {"date-parts": [[2019, 7, 25]]}
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = CrossrefCollector::new().with_base_url(server.uri());
+    let articles = collector
+        .collect("https://doi.org/10.1145/3292500.3330701", 5)
+        .await
+        .unwrap();
+
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].title, "Optuna");
+    assert_eq!(articles[0].published_date, "2019-07-25");
+}
+
+#[tokio::test]
+async fn test_crossref_collector_escapes_sici_doi() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/works/10.1002/(SICI)1097-4571(199806)49:8%3C693::AID-ASI4%3E3.0.CO;2-%23",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "status": "ok",
+            "message": {
+                "DOI": "10.1002/(SICI)1097-4571(199806)49:8<693::AID-ASI4>3.0.CO;2-#",
+                "title": ["Old article"]
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = CrossrefCollector::new().with_base_url(server.uri());
+    let articles = collector
+        .collect(
+            "doi:10.1002/(SICI)1097-4571(199806)49:8<693::AID-ASI4>3.0.CO;2-#",
+            5,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(articles[0].title, "Old article");
+}
+
+#[tokio::test]
+async fn test_pubmed_collector() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/esearch.fcgi"))
+        .and(query_param("term", "crispr"))
+        .and(query_param("tool", "xplorer"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"<?xml version="1.0" encoding="UTF-8" ?>
+<!DOCTYPE eSearchResult PUBLIC "-//NLM//DTD esearch 20060628//EN" "https://eutils.ncbi.nlm.nih.gov/eutils/dtd/20060628/esearch.dtd">
+<eSearchResult><Count>1</Count><RetMax>1</RetMax><IdList><Id>31452104</Id></IdList></eSearchResult>"#,
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/efetch.fcgi"))
+        .and(query_param("id", "31452104"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"<?xml version="1.0" ?>
+<!DOCTYPE PubmedArticleSet PUBLIC "-//NLM//DTD PubMedArticle, 1st January 2019//EN" "https://dtd.nlm.nih.gov/ncbi/pubmed/out/pubmed_190101.dtd">
+<PubmedArticleSet>
+  <PubmedArticle>
+    <MedlineCitation Status="MEDLINE" Owner="NLM">
+      <PMID Version="1">31452104</PMID>
+      <Article PubModel="Print">
+        <Journal>
+          <Title>Nature</Title>
+          <JournalIssue CitedMedium="Internet">
+            <PubDate><Year>2019</Year><Month>Aug</Month></PubDate>
+          </JournalIssue>
+        </Journal>
+        <ArticleTitle>Editing <i>in vivo</i> with CRISPR.</ArticleTitle>
+        <Abstract>
+          <AbstractText Label="BACKGROUND">Gene editing matters.</AbstractText>
+          <AbstractText Label="RESULTS">It works.</AbstractText>
+        </Abstract>
+        <AuthorList CompleteYN="Y">
+          <Author ValidYN="Y"><LastName>Doudna</LastName><ForeName>Jennifer A</ForeName><Initials>JA</Initials></Author>
+          <Author ValidYN="Y"><CollectiveName>CRISPR Consortium</CollectiveName></Author>
+        </AuthorList>
+      </Article>
+    </MedlineCitation>
+    <PubmedData>
+      <ArticleIdList>
+        <ArticleId IdType="pubmed">31452104</ArticleId>
+        <ArticleId IdType="doi">10.1038/s41586-019-1234-5</ArticleId>
+      </ArticleIdList>
+    </PubmedData>
+  </PubmedArticle>
+</PubmedArticleSet>"#,
+        ))
+        .mount(&server)
+        .await;
+
+    let collector = PubMedCollector::new()
+        .with_base_url(server.uri())
+        .with_tool("xplorer");
+    let articles = collector.collect("crispr", 5).await.unwrap();
+
+    assert_eq!(articles.len(), 1);
+    let article = &articles[0];
+    assert_eq!(article.title, "Editing in vivo with CRISPR.");
+    assert_eq!(
+        article.authors,
+        vec!["Jennifer A Doudna", "CRISPR Consortium"]
+    );
+    assert_eq!(article.url, "https://pubmed.ncbi.nlm.nih.gov/31452104/");
+    assert_eq!(article.published_date, "2019 Aug");
+    assert_eq!(
+        article.summary,
+        "BACKGROUND: Gene editing matters. RESULTS: It works."
+    );
+    assert_eq!(article.metadata["doi"], "10.1038/s41586-019-1234-5");
+}
+
+fn biorxiv_preprint(doi: &str, title: &str, category: &str) -> serde_json::Value {
+    serde_json::json!({
+        "doi": doi,
+        "title": title,
+        "authors": "Franklin, R.",
+        "date": "2024-01-02",
+        "category": category,
+        "abstract": "An abstract."
+    })
+}
+
+#[tokio::test]
+async fn test_biorxiv_collector_paginates_and_filters() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/details/biorxiv/2024-01-02/2024-01-02/0/json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "messages": [{"status": "ok", "cursor": 0, "count": 2, "total": "4"}],
+            "collection": [
+                biorxiv_preprint("10.1101/1", "Neural circuits", "neuroscience"),
+                biorxiv_preprint("10.1101/2", "Neural tissue", "cell biology"),
+            ]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/details/biorxiv/2024-01-02/2024-01-02/2/json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "messages": [{"status": "ok", "cursor": 2, "count": 2, "total": "4"}],
+            "collection": [
+                biorxiv_preprint("10.1101/3", "Glia", "neuroscience"),
+                biorxiv_preprint("10.1101/4", "Neural coding", "neuroscience"),
+            ]
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/details/biorxiv/2024-01-01/2024-01-01/0/json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "messages": [{"status": "ok", "cursor": 0, "count": 1, "total": "1"}],
+            "collection": [
+                biorxiv_preprint("10.1101/5", "Neural crest", "neuroscience"),
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = BioRxivCollector::biorxiv()
+        .with_base_url(server.uri())
+        .with_interval("2024-01-01/2024-01-02");
+    assert_eq!(collector.name(), "bioRxiv");
+
+    let articles = collector
+        .collect("category:neuroscience neural", 5)
+        .await
+        .unwrap();
+
+    // The newest day is scanned first, page by page, then the day before
+    assert_eq!(articles.len(), 3);
+    assert_eq!(articles[0].url, "https://doi.org/10.1101/1");
+    assert_eq!(articles[1].title, "Neural coding");
+    assert_eq!(articles[2].title, "Neural crest");
+
+    // Scanning stops at the newest day once enough matches are found
+    let articles = collector
+        .collect("category:neuroscience neural", 2)
+        .await
+        .unwrap();
+    assert_eq!(articles.len(), 2);
+}
+
+#[tokio::test]
+async fn test_hackernews_collector_thresholds() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search_by_date"))
+        .and(query_param("query", "rust async"))
+        .and(query_param("tags", "story"))
+        .and(query_param("numericFilters", "points>100,num_comments>=20"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "hits": [
+                {
+                    "objectID": "1",
+                    "title": "Async Rust in practice",
+                    "url": "https://example.com/async",
+                    "author": "pg",
+                    "points": 250,
+                    "num_comments": 42,
+                    "created_at": "2024-05-01T12:00:00.000Z"
+                },
+                {
+                    "objectID": "2",
+                    "title": "Ask HN: Async runtimes?",
+                    "url": null,
+                    "author": "dang",
+                    "points": 120,
+                    "num_comments": 80,
+                    "created_at": "2024-05-02T12:00:00.000Z"
+                }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = HackerNewsCollector::new().with_base_url(server.uri());
+    let articles = collector
+        .collect("rust points>100 comments>=20 async sort:date", 10)
+        .await
+        .unwrap();
+
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].url, "https://example.com/async");
+    assert_eq!(articles[0].metadata["points"], "250");
+    assert_eq!(articles[0].metadata["comments"], "42");
+    assert_eq!(articles[1].url, "https://news.ycombinator.com/item?id=2");
+}
+
+fn devto_article(id: u32) -> serde_json::Value {
+    serde_json::json!({
+        "id": id,
+        "title": format!("Rust tip #{}", id),
+        "url": format!("https://dev.to/ferris/tip-{}", id),
+        "user": {"name": "Ferris"}
+    })
+}
+
+#[tokio::test]
+async fn test_devto_collector_paginates() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/articles"))
+        .and(query_param("tag", "rust"))
+        .and(query_param("top", "7"))
+        .and(query_param("page", "1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([devto_article(1), devto_article(2)])),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/api/articles"))
+        .and(query_param("page", "2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(serde_json::json!([devto_article(3)])),
+        )
+        .mount(&server)
+        .await;
+
+    let collector = DevToCollector::new()
+        .with_base_url(server.uri())
+        .with_per_page(2);
+    let articles = collector.collect("rust top:7", 5).await.unwrap();
+
+    assert_eq!(articles.len(), 3);
+    assert_eq!(articles[2].title, "Rust tip #3");
+}
+
+#[tokio::test]
+async fn test_feed_collector_parses_all_formats() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/rss.xml"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+  <channel>
+    <title>Lab Blog</title>
+    <item>
+      <title>Scaling agents</title>
+      <link>https://lab.example/scaling</link>
+      <description><![CDATA[<p>How we scale <b>agents</b> &amp; more.</p>]]></description>
+      <pubDate>Mon, 03 Jun 2024 10:00:00 GMT</pubDate>
+      <dc:creator>Alan Turing</dc:creator>
+    </item>
+    <item>
+      <title>Office news</title>
+      <link>https://lab.example/office</link>
+      <description>Unrelated.</description>
+    </item>
+  </channel>
+</rss>"#,
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/atom.xml"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <title>Research</title>
+  <entry>
+    <title type="html">Agents at scale</title>
+    <link rel="alternate" href="https://research.example/agents"/>
+    <id>urn:uuid:1</id>
+    <published>2024-06-05T08:00:00Z</published>
+    <summary>Agents everywhere.</summary>
+    <author><name>Ada Lovelace</name></author>
+  </entry>
+</feed>"#,
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/feed.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "version": "https://jsonfeed.org/version/1.1",
+            "title": "Newsletter",
+            "items": [{
+                "id": "42",
+                "url": "https://news.example/42",
+                "title": "Weekly agents roundup",
+                "content_html": "<p>All about agents.</p>",
+                "date_published": "2024-06-04T08:00:00Z",
+                "authors": [{"name": "Grace Hopper"}]
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = FeedCollector::new(
+        "Lab Feeds",
+        vec![
+            format!("{}/rss.xml", server.uri()),
+            format!("{}/atom.xml", server.uri()),
+            format!("{}/feed.json", server.uri()),
+        ],
+    );
+    assert_eq!(collector.name(), "Lab Feeds");
+
+    let articles = collector.collect("agents", 10).await.unwrap();
+
+    assert_eq!(articles.len(), 3);
+    // Newest first across feeds
+    assert_eq!(articles[0].url, "https://research.example/agents");
+    assert_eq!(articles[0].authors, vec!["Ada Lovelace"]);
+    assert_eq!(articles[1].summary, "All about agents.");
+    assert_eq!(articles[2].summary, "How we scale agents & more.");
+    assert_eq!(articles[2].authors, vec!["Alan Turing"]);
+    assert_eq!(articles[2].source, "Lab Feeds");
+}
+
+#[tokio::test]
+async fn test_feed_collector_multiple_creators_and_xhtml_content() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/rss.xml"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"<?xml version="1.0"?>
+<rss version="2.0" xmlns:dc="http://purl.org/dc/elements/1.1/">
+  <channel>
+    <item>
+      <title>Joint work</title>
+      <dc:creator>Ada Lovelace</dc:creator>
+      <link>https://lab.example/joint</link>
+      <dc:creator>Charles Babbage</dc:creator>
+      <pubDate>Mon, 03 Jun 2024 10:00:00 GMT</pubDate>
+    </item>
+  </channel>
+</rss>"#,
+        ))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/atom.xml"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"<?xml version="1.0" encoding="utf-8"?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+  <entry>
+    <title>Rich post</title>
+    <link href="https://research.example/rich"/>
+    <updated>2024-06-05T08:00:00Z</updated>
+    <content type="xhtml">
+      <div xmlns="http://www.w3.org/1999/xhtml"><p>Markup <em>inside</em>.</p></div>
+    </content>
+  </entry>
+  <entry>
+    <title>Summarized post</title>
+    <link href="https://research.example/summarized"/>
+    <updated>2024-06-04T08:00:00Z</updated>
+    <summary type="html">&lt;p&gt;Short version.&lt;/p&gt;</summary>
+    <content type="xhtml"><div xmlns="http://www.w3.org/1999/xhtml">Long version.</div></content>
+  </entry>
+</feed>"#,
+        ))
+        .mount(&server)
+        .await;
+
+    let collector = FeedCollector::new(
+        "Lab Feeds",
+        vec![
+            format!("{}/rss.xml", server.uri()),
+            format!("{}/atom.xml", server.uri()),
+        ],
+    );
+    let articles = collector.collect("", 10).await.unwrap();
+
+    assert_eq!(articles.len(), 3);
+    assert_eq!(articles[0].url, "https://research.example/rich");
+    assert_eq!(articles[0].summary, "");
+    assert_eq!(articles[1].summary, "Short version.");
+    assert_eq!(articles[2].authors, vec!["Ada Lovelace", "Charles Babbage"]);
+}
+
+#[tokio::test]
+async fn test_github_collector_searches_repositories() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/repositories"))
+        .and(query_param("q", "topic:rust stars:>1000"))
+        .and(header("authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "total_count": 1,
+            "items": [{
+                "full_name": "tokio-rs/tokio",
+                "html_url": "https://github.com/tokio-rs/tokio",
+                "description": "A runtime for async Rust",
+                "owner": {"login": "tokio-rs"},
+                "stargazers_count": 25000,
+                "language": "Rust",
+                "topics": ["async", "rust"],
+                "pushed_at": "2024-06-01T00:00:00Z"
+            }]
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = GitHubCollector::new()
+        .with_base_url(server.uri())
+        .with_token("test-token");
+    let articles = collector
+        .collect("topic:rust stars:>1000", 5)
+        .await
+        .unwrap();
+
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].title, "tokio-rs/tokio");
+    assert_eq!(articles[0].metadata["stars"], "25000");
+    assert_eq!(articles[0].metadata["topics"], "async, rust");
+}
+
+#[tokio::test]
+async fn test_github_collector_lists_watched_releases() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/tokio-rs/tokio/releases"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "tag_name": "tokio-1.38.0",
+                "name": "Tokio v1.38.0",
+                "html_url": "https://github.com/tokio-rs/tokio/releases/tag/tokio-1.38.0",
+                "body": "### Added\n- stuff",
+                "author": {"login": "carllerche"},
+                "published_at": "2024-05-30T00:00:00Z",
+                "draft": false,
+                "prerelease": false
+            }
+        ])))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/serenity-rs/serenity/releases"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "tag_name": "v0.12.2",
+                "name": null,
+                "html_url": "https://github.com/serenity-rs/serenity/releases/tag/v0.12.2",
+                "body": null,
+                "published_at": "2024-06-02T00:00:00Z"
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let collector = GitHubCollector::new()
+        .with_base_url(server.uri())
+        .with_watched_repos(vec![
+            "tokio-rs/tokio".to_string(),
+            "serenity-rs/serenity".to_string(),
+        ]);
+    let articles = collector.collect("releases", 5).await.unwrap();
+
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].title, "serenity-rs/serenity v0.12.2");
+    assert_eq!(articles[1].title, "tokio-rs/tokio Tokio v1.38.0");
+    assert_eq!(articles[1].summary, "### Added - stuff");
+    assert_eq!(articles[1].metadata["tag"], "tokio-1.38.0");
+
+    // Anything but owner/name is rejected before it can reach another endpoint
+    let requests = server.received_requests().await.unwrap().len();
+    for query in [
+        "releases:../../user",
+        "releases:tokio-rs/tokio?per_page=1",
+        "releases:tokio-rs",
+        "releases:tokio-rs/tokio/issues",
+    ] {
+        assert!(collector.collect(query, 5).await.is_err(), "{}", query);
+    }
+    assert_eq!(server.received_requests().await.unwrap().len(), requests);
+}
+
+#[tokio::test]
+async fn test_openreview_collector_venue_with_decisions() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/notes"))
+        .and(query_param(
+            "invitation",
+            "ICLR.cc/2026/Conference/-/Submission",
+        ))
+        .and(query_param("offset", "0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "notes": [
+                {
+                    "id": "abc",
+                    "forum": "abc",
+                    "cdate": 1727740800000_i64,
+                    "content": {
+                        "title": {"value": "Diffusion for Everything"},
+                        "authors": {"value": ["Ada Lovelace", "Alan Turing"]},
+                        "abstract": {"value": "We diffuse."},
+                        "keywords": {"value": ["diffusion"]},
+                        "venue": {"value": "Submitted to ICLR 2026"}
+                    },
+                    "details": {
+                        "directReplies": [{
+                            "invitations": ["ICLR.cc/2026/Conference/Submission1/-/Decision"],
+                            "content": {"decision": {"value": "Accept (Oral)"}}
+                        }]
+                    }
+                },
+                {
+                    "id": "def",
+                    "forum": "def",
+                    "content": {
+                        "title": {"value": "Transformers Again"},
+                        "abstract": {"value": "Attention."},
+                        "venue": {"value": "Submitted to ICLR 2026"}
+                    }
+                }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = OpenReviewCollector::new().with_base_url(server.uri());
+    let articles = collector
+        .collect("ICLR.cc/2026/Conference diffusion", 5)
+        .await
+        .unwrap();
+
+    assert_eq!(articles.len(), 1);
+    let article = &articles[0];
+    assert_eq!(article.title, "Diffusion for Everything");
+    assert_eq!(article.url, "https://openreview.net/forum?id=abc");
+    assert_eq!(article.published_date, "2024-10-01");
+    assert_eq!(article.metadata["status"], "Accept (Oral)");
+}
+
+#[tokio::test]
+async fn test_dblp_collector_cleans_author_names() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/publ/api"))
+        .and(query_param("q", "wei wang"))
+        .and(query_param("format", "json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "result": {
+                "hits": {
+                    "@total": "2",
+                    "hit": [
+                        {
+                            "info": {
+                                "authors": {"author": [
+                                    {"@pid": "1", "text": "Wei Wang 0001"},
+                                    {"@pid": "2", "text": "Jane Doe"}
+                                ]},
+                                "title": "Graph Mining at Scale.",
+                                "venue": "KDD",
+                                "year": "2023",
+                                "type": "Conference and Workshop Papers",
+                                "ee": ["https://doi.org/10.1145/1", "https://example.com/pdf"],
+                                "url": "https://dblp.org/rec/conf/kdd/Wang23"
+                            }
+                        },
+                        {
+                            "info": {
+                                "authors": {"author": {"@pid": "1", "text": "Wei Wang 0001"}},
+                                "title": "Solo Paper.",
+                                "venue": ["CoRR", "arXiv"],
+                                "year": "2024",
+                                "url": "https://dblp.org/rec/journals/corr/Wang24"
+                            }
+                        }
+                    ]
+                }
+            }
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = DblpCollector::new().with_base_url(server.uri());
+    let articles = collector.collect("wei wang", 5).await.unwrap();
+
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].title, "Graph Mining at Scale");
+    assert_eq!(articles[0].authors, vec!["Wei Wang", "Jane Doe"]);
+    assert_eq!(articles[0].url, "https://doi.org/10.1145/1");
+    assert_eq!(articles[0].metadata["venue"], "KDD");
+    assert_eq!(articles[1].authors, vec!["Wei Wang"]);
+    assert_eq!(articles[1].url, "https://dblp.org/rec/journals/corr/Wang24");
+    assert_eq!(articles[1].metadata["venue"], "CoRR, arXiv");
+}
+
+const ANTHOLOGY_BIB: &str = r#"
+@proceedings{emnlp-2023-main,
+    title = "Proceedings of the 2023 Conference on Empirical Methods in Natural Language Processing",
+    year = "2023",
+    url = "https://aclanthology.org/2023.emnlp-main.0",
+}
+@inproceedings{lee-etal-2023-dense,
+    title = "Dense {R}etrieval for Open-Domain {QA}",
+    author = "Lee, Ana  and
+      M{\"u}ller, Jonas",
+    booktitle = "Proceedings of the 2023 Conference on Empirical Methods in Natural Language Processing",
+    month = dec,
+    year = "2023",
+    url = "https://aclanthology.org/2023.emnlp-main.12",
+    doi = "10.18653/v1/2023.emnlp-main.12",
+    abstract = "We study dense retrieval for question answering.",
+}
+@inproceedings{kim-2023-findings,
+    title = "Sparse Retrieval Revisited",
+    author = "Kim, Soo",
+    booktitle = "Findings of the Association for Computational Linguistics: EMNLP 2023",
+    year = "2023",
+    url = "https://aclanthology.org/2023.findings-emnlp.3",
+}
+@inproceedings{devlin-etal-2019-bert,
+    title = "{BERT}: Pre-training of Deep Bidirectional Transformers",
+    author = "Devlin, Jacob",
+    booktitle = "Proceedings of NAACL-HLT 2019",
+    month = jun,
+    year = "2019",
+    url = "https://aclanthology.org/N19-1423",
+    abstract = "Retrieval is not the point " # "of this abstract.",
+}
+"#;
+
+#[tokio::test]
+async fn test_acl_anthology_collector_indexes_cached_export() {
+    use flate2::write::GzEncoder;
+    use std::io::Write;
+
+    let server = MockServer::start().await;
+    let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(ANTHOLOGY_BIB.as_bytes()).unwrap();
+    Mock::given(method("GET"))
+        .and(path("/anthology+abstracts.bib.gz"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(encoder.finish().unwrap()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let cache_path = std::env::temp_dir()
+        .join(format!("xplorer-anthology-{}", std::process::id()))
+        .join("anthology.bib.gz");
+    let collector = AclAnthologyCollector::new()
+        .with_data_url(format!("{}/anthology+abstracts.bib.gz", server.uri()))
+        .with_cache_path(&cache_path);
+    assert_eq!(collector.name(), "ACL Anthology");
+
+    // Queries never download; they fail until the index has been built
+    assert!(collector.collect("retrieval", 10).await.is_err());
+    collector.refresh().await.unwrap();
+
+    let articles = collector
+        .collect("venue:emnlp retrieval", 10)
+        .await
+        .unwrap();
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].title, "Dense Retrieval for Open-Domain QA");
+    assert_eq!(articles[0].authors, vec!["Ana Lee", "Jonas Müller"]);
+    assert_eq!(articles[0].published_date, "2023-12");
+    assert_eq!(articles[0].metadata["anthology"], "2023.emnlp-main.12");
+    assert_eq!(
+        articles[1].url,
+        "https://aclanthology.org/2023.findings-emnlp.3"
+    );
+
+    // Accents are composed, so a precomposed `ü` finds the LaTeX-escaped name
+    let articles = collector.collect("müller", 10).await.unwrap();
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].title, "Dense Retrieval for Open-Domain QA");
+
+    // A fresh index is kept, so the export is downloaded only once
+    collector.refresh().await.unwrap();
+    let articles = collector.collect("venue:naacl", 10).await.unwrap();
+    assert_eq!(articles.len(), 1);
+    assert_eq!(
+        articles[0].title,
+        "BERT: Pre-training of Deep Bidirectional Transformers"
+    );
+    assert_eq!(
+        articles[0].summary,
+        "Retrieval is not the point of this abstract."
+    );
+    assert!(cache_path.exists());
+
+    // A fresh cache on disk is reused by a new instance without downloading
+    let restarted = AclAnthologyCollector::new()
+        .with_data_url(format!("{}/anthology+abstracts.bib.gz", server.uri()))
+        .with_cache_path(&cache_path);
+    restarted.refresh().await.unwrap();
+    let articles = restarted.collect("year:2019", 10).await.unwrap();
+    assert_eq!(articles.len(), 1);
+
+    std::fs::remove_dir_all(cache_path.parent().unwrap()).unwrap();
+}
+
+#[tokio::test]
+async fn test_acl_anthology_collector_replaces_only_valid_caches() {
+    use flate2::write::GzEncoder;
+    use std::io::Write;
+
+    let server = MockServer::start().await;
+    let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(ANTHOLOGY_BIB.as_bytes()).unwrap();
+    let export = encoder.finish().unwrap();
+    Mock::given(method("GET"))
+        .and(path("/anthology+abstracts.bib.gz"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(export.clone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/truncated.bib.gz"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(export[..20].to_vec()))
+        .mount(&server)
+        .await;
+
+    let cache_path = std::env::temp_dir()
+        .join(format!("xplorer-anthology-corrupt-{}", std::process::id()))
+        .join("anthology.bib.gz");
+    std::fs::create_dir_all(cache_path.parent().unwrap()).unwrap();
+
+    // A fresh but truncated cache is downloaded over instead of blocking the index
+    std::fs::write(&cache_path, &export[..20]).unwrap();
+    let collector = AclAnthologyCollector::new()
+        .with_data_url(format!("{}/anthology+abstracts.bib.gz", server.uri()))
+        .with_cache_path(&cache_path);
+    collector.refresh().await.unwrap();
+    assert_eq!(collector.collect("year:2019", 10).await.unwrap().len(), 1);
+    assert_eq!(std::fs::read(&cache_path).unwrap(), export);
+
+    // A truncated download leaves the cache alone, and the stale cache is used
+    let broken = AclAnthologyCollector::new()
+        .with_data_url(format!("{}/truncated.bib.gz", server.uri()))
+        .with_cache_path(&cache_path)
+        .with_refresh_interval(std::time::Duration::ZERO);
+    broken.refresh().await.unwrap();
+    assert_eq!(broken.collect("year:2019", 10).await.unwrap().len(), 1);
+    assert_eq!(std::fs::read(&cache_path).unwrap(), export);
+
+    std::fs::remove_dir_all(cache_path.parent().unwrap()).unwrap();
+}
+
+fn europe_pmc_record(id: u32) -> serde_json::Value {
+    serde_json::json!({
+        "id": id.to_string(),
+        "source": "MED",
+        "title": format!("Paper {}.", id),
+        "isOpenAccess": "N"
+    })
+}
+
+#[tokio::test]
+async fn test_europe_pmc_collector_follows_cursor() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("query", "crispr"))
+        .and(query_param("cursorMark", "*"))
+        .and(query_param("pageSize", "2"))
+        .and(query_param("resultType", "core"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "nextCursorMark": "AoE1",
+            "resultList": {"result": [europe_pmc_record(1), europe_pmc_record(2)]}
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search"))
+        .and(query_param("cursorMark", "AoE1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "nextCursorMark": "AoE2",
+            "resultList": {"result": [europe_pmc_record(3)]}
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = EuropePmcCollector::new()
+        .with_base_url(server.uri())
+        .with_page_size(2);
+    let articles = collector.collect("crispr", 5).await.unwrap();
+
+    assert_eq!(articles.len(), 3);
+    assert_eq!(articles[0].title, "Paper 1");
+    assert_eq!(articles[2].url, "https://europepmc.org/article/MED/3");
+}
+
+#[tokio::test]
+async fn test_zenodo_collector_maps_resource_type() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/records"))
+        .and(query_param("q", "speech"))
+        .and(query_param("type", "dataset"))
+        .and(query_param("sort", "mostrecent"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "hits": {"hits": [{
+                "id": 1234567,
+                "doi": "10.5281/zenodo.1234567",
+                "links": {"self_html": "https://zenodo.org/records/1234567"},
+                "metadata": {
+                    "title": "Speech Corpus",
+                    "publication_date": "2024-05-01",
+                    "description": "<p>Recordings &amp; transcripts.</p>",
+                    "creators": [{"name": "Doe, Jane"}, {"name": "ACME Lab"}],
+                    "resource_type": {"title": "Dataset", "type": "dataset"},
+                    "version": "v2"
+                }
+            }], "total": 1}
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = ZenodoCollector::new().with_base_url(server.uri());
+    let articles = collector.collect("type:dataset speech", 5).await.unwrap();
+
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].authors, vec!["Jane Doe", "ACME Lab"]);
+    assert_eq!(articles[0].url, "https://zenodo.org/records/1234567");
+    assert_eq!(articles[0].summary, "Recordings & transcripts.");
+    assert_eq!(articles[0].metadata["type"], "Dataset");
+    assert_eq!(articles[0].metadata["doi"], "10.5281/zenodo.1234567");
+}
+
+#[tokio::test]
+async fn test_huggingface_papers_collector_sorts_by_upvotes() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/daily_papers"))
+        .and(query_param("date", "2025-06-02"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "paper": {
+                    "id": "2506.00001",
+                    "title": "Small Models",
+                    "summary": "A study of small language models.",
+                    "authors": [{"name": "Ann Author"}],
+                    "upvotes": 12
+                },
+                "publishedAt": "2025-06-02T08:00:00.000Z",
+                "numComments": 1
+            },
+            {
+                "paper": {
+                    "id": "2506.00002",
+                    "title": "Large\n  Models",
+                    "summary": "A study of large language models.",
+                    "authors": [{"name": "Bo Author"}, {"name": "Cy Author"}],
+                    "upvotes": 87
+                },
+                "publishedAt": "2025-06-02T09:00:00.000Z",
+                "numComments": 4
+            },
+            {
+                "paper": {"id": "2506.00003", "title": "Robotics", "summary": "Arms.", "upvotes": 200}
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let collector = HuggingFacePapersCollector::new().with_base_url(server.uri());
+    let articles = collector
+        .collect("date:2025-06-02 language", 10)
+        .await
+        .unwrap();
+
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].title, "Large Models");
+    assert_eq!(articles[0].url, "https://huggingface.co/papers/2506.00002");
+    assert_eq!(articles[0].metadata["upvotes"], "87");
+    assert_eq!(articles[0].metadata["comments"], "4");
+    assert_eq!(
+        articles[0].metadata["arxiv"],
+        "https://arxiv.org/abs/2506.00002"
+    );
+    assert_eq!(articles[1].title, "Small Models");
+}
+
+#[tokio::test]
+async fn test_huggingface_papers_collector_keeps_newest_day_without_date() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/daily_papers"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "paper": {"id": "2506.00010", "title": "Older Paper", "upvotes": 300},
+                "publishedAt": "2025-06-01T08:00:00.000Z"
+            },
+            {
+                "paper": {"id": "2506.00011", "title": "Newer Paper", "upvotes": 5},
+                "publishedAt": "2025-06-02T08:00:00.000Z"
+            },
+            {
+                "paper": {"id": "2506.00012", "title": "Newest Paper", "upvotes": 9},
+                "publishedAt": "2025-06-02T10:00:00.000Z"
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let collector = HuggingFacePapersCollector::new().with_base_url(server.uri());
+    let articles = collector.collect("", 10).await.unwrap();
+
+    let titles: Vec<&str> = articles.iter().map(|a| a.title.as_str()).collect();
+    assert_eq!(titles, vec!["Newest Paper", "Newer Paper"]);
+}
+
+fn reddit_post(id: &str, is_self: bool) -> serde_json::Value {
+    serde_json::json!({"kind": "t3", "data": {
+        "title": format!("Post {}", id),
+        "permalink": format!("/r/rust/comments/{}/post/", id),
+        "subreddit": "rust",
+        "is_self": is_self
+    }})
+}
+
+#[tokio::test]
+async fn test_reddit_collector_filters_self_posts_across_pages() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/r/rust/top.json"))
+        .and(header("User-Agent", "test:xplorer:0.1.0"))
+        .and(query_param("t", "week"))
+        .and(query_param("after", "t3_b"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {"after": null, "children": [reddit_post("c", false)]}
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/r/rust/top.json"))
+        .and(header("User-Agent", "test:xplorer:0.1.0"))
+        .and(query_param("t", "week"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "data": {"after": "t3_b", "children": [reddit_post("a", false), reddit_post("b", true)]}
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = RedditCollector::new("test:xplorer:0.1.0").with_base_url(server.uri());
+    let articles = collector
+        .collect("r/rust top t:week -self", 2)
+        .await
+        .unwrap();
+
+    let titles: Vec<&str> = articles.iter().map(|a| a.title.as_str()).collect();
+    assert_eq!(titles, vec!["Post a", "Post c"]);
+}
+
+fn lobsters_story(id: u32, title: &str) -> serde_json::Value {
+    serde_json::json!({
+        "short_id_url": format!("https://lobste.rs/s/s{}", id),
+        "title": title,
+        "url": format!("https://example.com/{}", id)
+    })
+}
+
+#[tokio::test]
+async fn test_lobsters_collector_tag_pages() {
+    let server = MockServer::start().await;
+    let first_page: Vec<_> = (1..=25)
+        .map(|id| lobsters_story(id, if id == 5 { "Async Rust" } else { "Other" }))
+        .collect();
+    Mock::given(method("GET"))
+        .and(path("/t/rust,plt.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(first_page))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/t/rust,plt/page/2.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(vec![lobsters_story(26, "Async closures in Rust")]),
+        )
+        .mount(&server)
+        .await;
+
+    let collector = LobstersCollector::new().with_base_url(server.uri());
+    let articles = collector
+        .collect("tag:rust tag:plt async", 10)
+        .await
+        .unwrap();
+
+    let urls: Vec<&str> = articles.iter().map(|a| a.url.as_str()).collect();
+    assert_eq!(
+        urls,
+        vec!["https://example.com/5", "https://example.com/26"]
+    );
+}
+
+#[tokio::test]
+async fn test_inspire_hep_collector_summarizes_collaborations() {
+    let server = MockServer::start().await;
+    let atlas_authors: Vec<_> = (0..30)
+        .map(|i| serde_json::json!({"full_name": format!("Physicist, Number {}", i)}))
+        .collect();
+    Mock::given(method("GET"))
+        .and(path("/literature"))
+        .and(query_param("q", "cn atlas and t higgs"))
+        .and(query_param("sort", "mostcited"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "hits": {"hits": [
+                {
+                    "id": "1124337",
+                    "metadata": {
+                        "titles": [{"title": "Observation of a new particle"}],
+                        "authors": atlas_authors,
+                        "author_count": 2932,
+                        "collaborations": [{"value": "ATLAS"}],
+                        "arxiv_eprints": [{"value": "1207.7214", "categories": ["hep-ex"]}],
+                        "citation_count": 15000,
+                        "dois": [{"value": "10.1016/j.physletb.2012.08.020"}],
+                        "abstracts": [{"value": "A search  for the Higgs boson."}],
+                        "earliest_date": "2012-07-31",
+                        "publication_info": [{"journal_title": "Phys.Lett.B", "journal_volume": "716", "year": 2012}]
+                    }
+                },
+                {
+                    "id": "42",
+                    "metadata": {
+                        "titles": [{"title": "A small paper"}],
+                        "authors": [{"full_name": "Witten, Edward"}],
+                        "author_count": 1
+                    }
+                }
+            ]}
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = InspireHepCollector::new().with_base_url(server.uri());
+    let articles = collector
+        .collect("cn atlas and t higgs sort:mostcited", 5)
+        .await
+        .unwrap();
+
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].authors, vec!["ATLAS Collaboration"]);
+    assert_eq!(articles[0].url, "https://inspirehep.net/literature/1124337");
+    assert_eq!(articles[0].metadata["citations"], "15000");
+    assert_eq!(articles[0].metadata["author count"], "2932");
+    assert_eq!(articles[0].metadata["arxiv"], "1207.7214 [hep-ex]");
+    assert_eq!(articles[0].metadata["journal"], "Phys.Lett.B 716 (2012)");
+    assert_eq!(articles[0].summary, "A search for the Higgs boson.");
+    assert_eq!(articles[1].authors, vec!["Edward Witten"]);
+    assert!(!articles[1].metadata.contains_key("author count"));
+}
+
+#[tokio::test]
+async fn test_ads_collector_sends_token_and_maps_bibcodes() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/query"))
+        .and(header("Authorization", "Bearer ads-token"))
+        .and(query_param("q", "abs:\"dark energy\""))
+        .and(query_param("sort", "citation_count desc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "responseHeader": {"status": 0},
+            "response": {"numFound": 1, "start": 0, "docs": [{
+                "bibcode": "1998AJ....116.1009R",
+                "title": ["Observational Evidence from Supernovae"],
+                "author": ["Riess, Adam G.", "Filippenko, Alexei V."],
+                "pubdate": "1998-09-00",
+                "pub": "The Astronomical Journal",
+                "doi": ["10.1086/300499"],
+                "identifier": ["1998AJ....116.1009R", "arXiv:astro-ph/9805201"],
+                "citation_count": 17000,
+                "abstract": "We present  observations."
+            }]}
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = AdsCollector::new("ads-token").with_base_url(server.uri());
+    let articles = collector
+        .collect("abs:\"dark energy\" sort:citations", 5)
+        .await
+        .unwrap();
+
+    assert_eq!(articles.len(), 1);
+    assert_eq!(
+        articles[0].authors,
+        vec!["Adam G. Riess", "Alexei V. Filippenko"]
+    );
+    assert_eq!(
+        articles[0].url,
+        "https://ui.adsabs.harvard.edu/abs/1998AJ....116.1009R/abstract"
+    );
+    assert_eq!(articles[0].published_date, "1998-09");
+    assert_eq!(articles[0].metadata["bibcode"], "1998AJ....116.1009R");
+    assert_eq!(articles[0].metadata["doi"], "10.1086/300499");
+    assert_eq!(articles[0].metadata["citations"], "17000");
+    assert_eq!(articles[0].metadata["arxiv"], "astro-ph/9805201");
+}
+
+#[tokio::test]
+async fn test_core_collector_retries_after_rate_limit() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/works"))
+        .and(header("Authorization", "Bearer core-key"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("X-RateLimit-Remaining", "0")
+                .insert_header("X-RateLimit-Retry-After", "1"),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search/works"))
+        .and(query_param("q", "graph neural"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("X-RateLimit-Remaining", "9")
+                .set_body_json(serde_json::json!({
+                    "totalHits": 1,
+                    "results": [{
+                        "id": 12345,
+                        "title": "Graph Neural Networks",
+                        "authors": [{"name": "Doe, Jane"}],
+                        "abstract": "We study  GNNs.",
+                        "doi": "10.1000/gnn",
+                        "downloadUrl": "https://core.ac.uk/download/12345.pdf",
+                        "links": [{"type": "display", "url": "https://core.ac.uk/works/12345"}],
+                        "publishedDate": "2023-04-01T00:00:00",
+                        "journals": [{"title": "Journal of Graphs"}]
+                    }]
+                })),
+        )
+        .mount(&server)
+        .await;
+
+    let collector = CoreCollector::new()
+        .with_base_url(server.uri())
+        .with_api_key("core-key");
+    let articles = collector.collect("graph neural", 5).await.unwrap();
+
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].url, "https://core.ac.uk/works/12345");
+    assert_eq!(articles[0].published_date, "2023-04-01");
+    assert_eq!(articles[0].summary, "We study GNNs.");
+    assert_eq!(
+        articles[0].metadata["download"],
+        "https://core.ac.uk/download/12345.pdf"
+    );
+    assert_eq!(articles[0].metadata["journal"], "Journal of Graphs");
+}
+
+#[tokio::test]
+async fn test_stackexchange_collector_gzip_and_backoff() {
+    use flate2::write::GzEncoder;
+    use std::io::Write;
+
+    let body = serde_json::json!({
+        "items": [{
+            "tags": ["rust", "rust-tokio"],
+            "owner": {"display_name": "J&amp;K"},
+            "is_answered": true,
+            "answer_count": 2,
+            "accepted_answer_id": 99,
+            "score": 15,
+            "creation_date": 1717200000,
+            "question_id": 1,
+            "link": "https://stackoverflow.com/questions/1/spawn",
+            "title": "Why does &quot;spawn&quot; need &#39;static?"
+        }],
+        "has_more": false,
+        "quota_remaining": 299,
+        "backoff": 1
+    });
+    let mut encoder = GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(body.to_string().as_bytes()).unwrap();
+
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/advanced"))
+        .and(query_param("site", "stackoverflow"))
+        .and(query_param("tagged", "rust;rust-tokio"))
+        .and(query_param("sort", "votes"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Content-Encoding", "gzip")
+                .insert_header("Content-Type", "application/json")
+                .set_body_bytes(encoder.finish().unwrap()),
+        )
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let collector = StackExchangeCollector::new().with_base_url(server.uri());
+    let articles = collector
+        .collect("tag:rust tag:rust-tokio", 5)
+        .await
+        .unwrap();
+
+    assert_eq!(articles.len(), 1);
+    assert_eq!(articles[0].title, "Why does \"spawn\" need 'static?");
+    assert_eq!(articles[0].authors, vec!["J&K"]);
+    assert_eq!(articles[0].metadata["score"], "15");
+    assert_eq!(articles[0].metadata["status"], "accepted answer");
+    assert_eq!(articles[0].metadata["tags"], "rust, rust-tokio");
+
+    // The next request waits out the one second backoff
+    let started = std::time::Instant::now();
+    collector
+        .collect("tag:rust tag:rust-tokio", 5)
+        .await
+        .unwrap();
+    assert!(started.elapsed() >= std::time::Duration::from_millis(900));
+}
+
+#[tokio::test]
+async fn test_stackexchange_collector_rejects_invalid_days() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/advanced"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({"items": []})))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let collector = StackExchangeCollector::new().with_base_url(server.uri());
+    for query in [
+        "days:999999999999999",
+        "days:4000000000",
+        "days:-3",
+        "days:week",
+    ] {
+        assert!(collector.collect(query, 5).await.is_err(), "{}", query);
+    }
+}
+
+#[tokio::test]
+async fn test_ietf_collector_represents_draft_revisions() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/api/v1/doc/document/"))
+        .and(query_param("group__acronym", "quic"))
+        .and(query_param("type__in", "draft,rfc"))
+        .and(query_param("order_by", "-time"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "meta": {"limit": 5, "offset": 0, "total_count": 2},
+            "objects": [
+                {
+                    "name": "draft-ietf-quic-multipath",
+                    "rev": "07",
+                    "title": "Multipath Extension for QUIC",
+                    "abstract": "This document specifies\n   a multipath extension.",
+                    "time": "2024-03-04T12:00:00Z",
+                    "type": "/api/v1/name/doctypename/draft/",
+                    "pages": 40,
+                    "std_level": null
+                },
+                {
+                    "name": "rfc9000",
+                    "rev": "",
+                    "title": "QUIC: A UDP-Based Multiplexed and Secure Transport",
+                    "abstract": "",
+                    "time": "2021-05-27T00:00:00Z",
+                    "type": "/api/v1/name/doctypename/rfc/",
+                    "pages": 151,
+                    "std_level": "/api/v1/name/stdlevelname/ps/"
+                }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let collector = IetfCollector::new().with_base_url(server.uri());
+    let articles = collector.collect("wg:quic", 5).await.unwrap();
+
+    assert_eq!(articles.len(), 2);
+    assert_eq!(
+        articles[0].url,
+        "https://datatracker.ietf.org/doc/draft-ietf-quic-multipath/07/"
+    );
+    assert_eq!(
+        articles[0].metadata["draft"],
+        "draft-ietf-quic-multipath-07"
+    );
+    assert_eq!(articles[0].metadata["revision"], "07");
+    assert_eq!(articles[0].metadata["wg"], "quic");
+    assert_eq!(
+        articles[0].summary,
+        "This document specifies a multipath extension."
+    );
+    assert_eq!(articles[1].url, "https://datatracker.ietf.org/doc/rfc9000/");
+    assert_eq!(articles[1].metadata["rfc"], "9000");
+    assert_eq!(articles[1].metadata["status"], "ps");
+    assert!(!articles[1].metadata.contains_key("revision"));
+}
+
+fn scrape_config(url: String) -> ScrapeConfig {
+    ScrapeConfig {
+        name: "Lab News".to_string(),
+        url,
+        description: None,
+        item: "article.news".to_string(),
+        title: Some("h2".to_string()),
+        link: Some("h2 a".to_string()),
+        date: None,
+        summary: Some("p.teaser".to_string()),
+    }
+}
+
+#[tokio::test]
+async fn test_scrape_collector_filters_scraped_page() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/robots.txt"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_string("User-agent: *\nDisallow: /news/drafts\nAllow: /news/\n"),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/news/"))
+        .respond_with(ResponseTemplate::new(200).set_body_string(
+            r#"<html><body>
+<article class="news">
+  <h2><a href="2024/robot-arm.html">A new robot arm</a></h2>
+</article>
+<article class="news">
+  <h2><a href="/events/open-day">Open day</a></h2>
+  <p class="teaser">Visit the lab.</p>
+</article>
+</body></html>"#,
+        ))
+        .mount(&server)
+        .await;
+
+    let collector = ScrapeCollector::new(scrape_config(format!("{}/news/", server.uri()))).unwrap();
+    assert_eq!(collector.collect("", 10).await.unwrap().len(), 2);
+
+    let articles = collector.collect("robot", 10).await.unwrap();
+    assert_eq!(articles.len(), 1);
+    assert_eq!(
+        articles[0].url,
+        format!("{}/news/2024/robot-arm.html", server.uri())
+    );
+}
+
+#[tokio::test]
+async fn test_scrape_collector_respects_robots_txt() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/robots.txt"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_string(
+                "User-agent: *\nAllow: /\n\nUser-agent: xplorer\nDisallow: /news\n",
+            ),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/news/"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<html></html>"))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let collector = ScrapeCollector::new(scrape_config(format!("{}/news/", server.uri()))).unwrap();
+    assert!(collector.collect("", 10).await.is_err());
+}
+
+#[tokio::test]
+async fn test_mastodon_collector_extracts_links_and_dedups_boosts() {
+    let server = MockServer::start().await;
+    let account = serde_json::json!({
+        "id": "1", "acct": "ada@sigmoid.social", "display_name": "Ada"
+    });
+    let paper_post = serde_json::json!({
+        "id": "100",
+        "uri": "https://sigmoid.social/users/ada/statuses/100",
+        "url": "https://sigmoid.social/@ada/100",
+        "created_at": "2024-06-03T10:00:00.000Z",
+        "content": "<p>Our new paper &amp; code! <a href=\"https://arxiv.org/abs/2406.00001\">arxiv.org/abs/2406.00001</a> <a href=\"https://sigmoid.social/tags/ml\" class=\"mention hashtag\" rel=\"tag\">#<span>ml</span></a></p>",
+        "account": account,
+        "reblog": null,
+        "card": {
+            "url": "https://arxiv.org/abs/2406.00001",
+            "title": "Scaling Agents",
+            "description": "We scale agents.",
+            "author_name": "",
+            "provider_name": "arXiv.org"
+        },
+        "reblogs_count": 12,
+        "favourites_count": 30
+    });
+    Mock::given(method("GET"))
+        .and(path("/api/v1/timelines/tag/ml"))
+        .and(query_param("limit", "40"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!([
+            {
+                "id": "300",
+                "uri": "https://mastodon.social/users/bob/statuses/300/activity",
+                "url": null,
+                "created_at": "2024-06-04T09:00:00.000Z",
+                "content": "",
+                "account": {"id": "2", "acct": "bob", "display_name": ""},
+                "reblog": paper_post,
+                "card": null
+            },
+            {
+                "id": "200",
+                "uri": "https://mastodon.social/users/bob/statuses/200",
+                "url": "https://mastodon.social/@bob/200",
+                "created_at": "2024-06-03T12:00:00.000Z",
+                "content": "<p>Thanks <span class=\"h-card\"><a href=\"https://sigmoid.social/@ada\" class=\"u-url mention\">@<span>ada</span></a></span>, see <a href=\"https://blog.example/post\">blog.example/post</a></p>",
+                "account": {"id": "2", "acct": "bob", "display_name": ""},
+                "reblog": null,
+                "card": null
+            },
+            paper_post,
+            {
+                "id": "50",
+                "uri": "https://mastodon.social/users/bob/statuses/50",
+                "url": "https://mastodon.social/@bob/50",
+                "created_at": "2024-06-01T12:00:00.000Z",
+                "content": "<p>No links here</p>",
+                "account": {"id": "2", "acct": "bob", "display_name": ""},
+                "reblog": null,
+                "card": null
+            }
+        ])))
+        .mount(&server)
+        .await;
+
+    let collector = MastodonCollector::new().with_base_url(server.uri());
+    let articles = collector.collect("#ml", 10).await.unwrap();
+
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].url, "https://blog.example/post");
+    assert_eq!(articles[0].title, "https://blog.example/post");
+    assert_eq!(articles[0].summary, "Thanks @ada, see blog.example/post");
+    assert_eq!(articles[0].metadata["posted by"], "@bob");
+    assert_eq!(articles[1].url, "https://arxiv.org/abs/2406.00001");
+    assert_eq!(articles[1].title, "Scaling Agents");
+    assert_eq!(
+        articles[1].summary,
+        "Our new paper & code! arxiv.org/abs/2406.00001 #ml"
+    );
+    assert_eq!(
+        articles[1].metadata["posted by"],
+        "Ada (@ada@sigmoid.social)"
+    );
+    assert_eq!(
+        articles[1].metadata["post"],
+        "https://sigmoid.social/@ada/100"
+    );
+    assert_eq!(articles[1].metadata["boosts"], "12");
+
+    let filtered = collector.collect("#ml paper", 10).await.unwrap();
+    assert_eq!(filtered.len(), 1);
+
+    // Hashtags are path segments, so anything that is not a hashtag is refused
+    let requests = server.received_requests().await.unwrap().len();
+    for query in ["#ml/../../accounts", "#ml?limit=1", "#"] {
+        assert!(collector.collect(query, 10).await.is_err(), "{}", query);
+    }
+    assert_eq!(server.received_requests().await.unwrap().len(), requests);
+}
+
+#[tokio::test]
+async fn test_bluesky_collector_reads_embeds_and_link_facets() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/xrpc/com.atproto.server.createSession"))
+        .and(body_json(serde_json::json!({
+            "identifier": "ada.bsky.social",
+            "password": "app-pass"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "accessJwt": "access-token",
+            "refreshJwt": "refresh-token",
+            "handle": "ada.bsky.social",
+            "did": "did:plc:ada"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/xrpc/app.bsky.feed.searchPosts"))
+        .and(header("authorization", "Bearer access-token"))
+        .and(query_param("q", "diffusion"))
+        .and(query_param("sort", "latest"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+            "posts": [
+                {
+                    "uri": "at://did:plc:ada/app.bsky.feed.post/3kabc",
+                    "author": {"handle": "ada.bsky.social", "displayName": "Ada"},
+                    "record": {
+                        "text": "New diffusion paper!",
+                        "createdAt": "2024-06-03T10:00:00.000Z"
+                    },
+                    "embed": {
+                        "$type": "app.bsky.embed.external#view",
+                        "external": {
+                            "uri": "https://arxiv.org/abs/2406.00001",
+                            "title": "Faster Diffusion",
+                            "description": "We make diffusion faster."
+                        }
+                    },
+                    "likeCount": 42,
+                    "repostCount": 7
+                },
+                {
+                    "uri": "at://did:plc:bob/app.bsky.feed.post/3kdef",
+                    "author": {"handle": "bob.bsky.social"},
+                    "record": {
+                        "text": "@ada.bsky.social diffusion blog.example/post",
+                        "createdAt": "2024-06-03T09:00:00.000Z",
+                        "facets": [
+                            {
+                                "index": {"byteStart": 0, "byteEnd": 16},
+                                "features": [{"$type": "app.bsky.richtext.facet#mention", "did": "did:plc:ada"}]
+                            },
+                            {
+                                "index": {"byteStart": 27, "byteEnd": 44},
+                                "features": [{"$type": "app.bsky.richtext.facet#link", "uri": "https://blog.example/post"}]
+                            }
+                        ]
+                    },
+                    "embed": {"$type": "app.bsky.embed.images#view", "images": []}
+                },
+                {
+                    "uri": "at://did:plc:carol/app.bsky.feed.post/3kghi",
+                    "author": {"handle": "carol.bsky.social"},
+                    "record": {
+                        "text": "Everyone is talking about diffusion",
+                        "createdAt": "2024-06-03T08:00:00.000Z"
+                    }
+                },
+                {
+                    "uri": "at://did:plc:dan/app.bsky.feed.post/3kjkl",
+                    "author": {"handle": "dan.bsky.social"},
+                    "record": {
+                        "text": "Worth a read",
+                        "createdAt": "2024-06-03T07:00:00.000Z"
+                    },
+                    "embed": {
+                        "$type": "app.bsky.embed.recordWithMedia#view",
+                        "record": {},
+                        "media": {
+                            "$type": "app.bsky.embed.external#view",
+                            "external": {"uri": "https://arxiv.org/abs/2406.00001", "title": "Faster Diffusion"}
+                        }
+                    }
+                }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let collector =
+        BlueskyCollector::new("ada.bsky.social", "app-pass").with_base_url(server.uri());
+    let articles = collector.collect("diffusion", 10).await.unwrap();
+
+    assert_eq!(articles.len(), 2);
+    assert_eq!(articles[0].url, "https://arxiv.org/abs/2406.00001");
+    assert_eq!(articles[0].title, "Faster Diffusion");
+    assert_eq!(articles[0].summary, "New diffusion paper!");
+    assert_eq!(
+        articles[0].metadata["post"],
+        "https://bsky.app/profile/ada.bsky.social/post/3kabc"
+    );
+    assert_eq!(articles[0].metadata["posted by"], "Ada (@ada.bsky.social)");
+    assert_eq!(articles[0].metadata["likes"], "42");
+    assert_eq!(articles[1].url, "https://blog.example/post");
+    assert_eq!(articles[1].metadata["posted by"], "@bob.bsky.social");
+
+    // The session is reused rather than created per request
+    collector.collect("diffusion", 10).await.unwrap();
+}